@@ -7,12 +7,16 @@ class GitUrlDict(TypedDict):
     subgroups: list[str]
     organization: str | None
     fullname: str
+    helper: str | None
     scheme: str
     auth_user: str | None
     auth_token: str | None
     port: int | None
     path: str | None
+    path_base: str | None
     git_suffix: bool
     scheme_prefix: bool
+    provider: str
+    extra: str | None
 
 def parse(url: str) -> GitUrlDict: ...
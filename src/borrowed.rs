@@ -0,0 +1,329 @@
+use crate::source::UrlSource;
+use crate::{GitUrl, GitUrlParseError, HostRegistry, PathBase, Provider, Scheme};
+use alloc::borrow::Cow;
+use alloc::format;
+
+/// GitUrlRef is a [`GitUrl`] whose parts are slices of the input it was
+/// parsed from
+///
+/// Common remote urls, such as `https://host/org/repo.git` or
+/// `git@host:org/repo.git`, are split without allocating. Inputs the `url` crate
+/// would normalize, such as uppercase hosts, percent-encoded characters or
+/// provider specific layouts, are parsed like [`GitUrl::parse`] does and their
+/// parts are owned.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GitUrlRef<'a> {
+    /// The input the url was parsed from
+    pub input: &'a str,
+    /// The fully qualified domain name (FQDN) or IP of the repo
+    pub host: Option<Cow<'a, str>>,
+    /// The name of the repo
+    pub name: Cow<'a, str>,
+    /// The owner/account/project name
+    pub owner: Option<Cow<'a, str>>,
+    /// subgroups, if any.
+    pub subgroups: Option<Cow<'a, str>>,
+    /// The organization name.
+    pub organization: Option<Cow<'a, str>>,
+    /// The full name of the repo, formatted as "owner/name"
+    pub fullname: Cow<'a, str>,
+    /// The remote helper of a `<transport>::<address>` url
+    pub helper: Option<Cow<'a, str>>,
+    /// The git url scheme
+    pub scheme: Scheme,
+    /// The authentication user
+    pub auth_user: Option<Cow<'a, str>>,
+    /// The oauth token (could appear in the https urls)
+    pub auth_token: Option<Cow<'a, str>>,
+    /// The non-conventional port where git service is hosted
+    pub port: Option<u16>,
+    /// The path to repo w/ respect to user + hostname
+    pub path: Cow<'a, str>,
+    /// Whether the path is relative to a home directory
+    pub path_base: PathBase,
+    /// Indicate if url uses the .git suffix
+    pub git_suffix: bool,
+    /// Indicate if url explicitly uses its scheme
+    pub scheme_prefix: bool,
+    /// The git hosting service detected from the host and path
+    pub provider: Provider,
+    /// Trailing path after the repo that is not part of its identity
+    pub extra: Option<Cow<'a, str>>,
+}
+
+impl<'a> GitUrlRef<'a> {
+    /// Returns a `Result<GitUrlRef>` with the same parts [`GitUrl::parse`]
+    /// returns for `input`
+    pub fn parse(input: &'a str) -> Result<GitUrlRef<'a>, GitUrlParseError> {
+        match split(input) {
+            Some(url) => Ok(url),
+            None => GitUrl::parse_parts(input, &HostRegistry::default())
+                .map(|url| GitUrlRef::from_git_url(input, url)),
+        }
+    }
+
+    /// Returns whether every part of the url is a slice of the input
+    pub fn is_borrowed(&self) -> bool {
+        let borrowed = |part: &Option<Cow<str>>| !matches!(part, Some(Cow::Owned(_)));
+        borrowed(&self.host)
+            && matches!(self.name, Cow::Borrowed(_))
+            && borrowed(&self.owner)
+            && borrowed(&self.subgroups)
+            && borrowed(&self.organization)
+            && matches!(self.fullname, Cow::Borrowed(_))
+            && borrowed(&self.helper)
+            && borrowed(&self.auth_user)
+            && borrowed(&self.auth_token)
+            && matches!(self.path, Cow::Borrowed(_))
+            && borrowed(&self.extra)
+    }
+
+    /// Returns the [`GitUrl`] that [`GitUrl::parse`] returns for the input,
+    /// copying only the parts that are borrowed
+    pub fn into_git_url(self) -> GitUrl {
+        let owned = |part: Option<Cow<str>>| part.map(Cow::into_owned);
+        let url = GitUrl {
+            host: owned(self.host),
+            name: self.name.into_owned(),
+            owner: owned(self.owner),
+            subgroups: owned(self.subgroups),
+            organization: owned(self.organization),
+            fullname: self.fullname.into_owned(),
+            helper: owned(self.helper),
+            scheme: self.scheme,
+            auth_user: owned(self.auth_user),
+            auth_token: owned(self.auth_token),
+            port: self.port,
+            path: self.path.into_owned(),
+            path_base: self.path_base,
+            git_suffix: self.git_suffix,
+            scheme_prefix: self.scheme_prefix,
+            provider: self.provider,
+            extra: owned(self.extra),
+            source: None,
+        };
+        GitUrl {
+            source: Some(UrlSource::new(self.input, &url)),
+            ..url
+        }
+    }
+
+    /// Wraps the parts of a url parsed without its source
+    fn from_git_url(input: &'a str, url: GitUrl) -> GitUrlRef<'a> {
+        GitUrlRef {
+            input,
+            host: url.host.map(Cow::Owned),
+            name: Cow::Owned(url.name),
+            owner: url.owner.map(Cow::Owned),
+            subgroups: url.subgroups.map(Cow::Owned),
+            organization: url.organization.map(Cow::Owned),
+            fullname: Cow::Owned(url.fullname),
+            helper: url.helper.map(Cow::Owned),
+            scheme: url.scheme,
+            auth_user: url.auth_user.map(Cow::Owned),
+            auth_token: url.auth_token.map(Cow::Owned),
+            port: url.port,
+            path: Cow::Owned(url.path),
+            path_base: url.path_base,
+            git_suffix: url.git_suffix,
+            scheme_prefix: url.scheme_prefix,
+            provider: url.provider,
+            extra: url.extra.map(Cow::Owned),
+        }
+    }
+}
+
+/// Splits an url the `url` crate would not change into slices, or returns
+/// `None` for [`GitUrl::parse`] to handle
+fn split(input: &str) -> Option<GitUrlRef<'_>> {
+    // Trailing slashes are dropped, as in `normalize_url`
+    let url = input.trim_end_matches('/');
+
+    let (scheme, authority, path, scheme_prefix) = match url.split_once("://") {
+        Some((scheme, rest)) => {
+            let scheme = match scheme {
+                "https" => Scheme::Https,
+                "http" => Scheme::Http,
+                "ssh" => Scheme::Ssh,
+                "git" => Scheme::Git,
+                "git+ssh" => Scheme::GitSsh,
+                "git+https" => Scheme::GitHttps,
+                "git+http" => Scheme::GitHttp,
+                _ => return None,
+            };
+            let (authority, path) = rest.split_at(rest.find('/')?);
+            // Normalized ssh urls can always have their first '/' removed
+            let path = match scheme {
+                Scheme::Ssh => &path[1..],
+                _ => path,
+            };
+            (scheme, authority, path, true)
+        }
+        // An scp-like url, which is normalized to `ssh://` with a single `:`
+        None => {
+            let (authority, path) = url.split_once(':')?;
+            // A second `:` would be taken for a port, and a second `@` for a
+            // local path
+            if path.contains([':', '@']) || path.starts_with('/') || !authority.contains('@') {
+                return None;
+            }
+            (Scheme::Ssh, authority, path, false)
+        }
+    };
+
+    let (userinfo, host_port) = match authority.rsplit_once('@') {
+        Some((userinfo, host_port)) => (Some(userinfo), host_port),
+        None => (None, authority),
+    };
+    let (auth_user, auth_token) = match userinfo {
+        Some(userinfo) => match userinfo.split_once(':') {
+            Some((user, token)) => (user, Some(token)),
+            None => (userinfo, None),
+        },
+        None => ("", None),
+    };
+    if userinfo.is_some() && !is_unreserved(auth_user)
+        || auth_token.is_some_and(|token| !is_unreserved(token))
+    {
+        return None;
+    }
+
+    let (host, port) = match host_port.split_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (host_port, None),
+    };
+    if !is_plain_host(host) {
+        return None;
+    }
+    let port = match port {
+        Some(port) if port.starts_with('0') => return None,
+        Some(port) => Some(port.parse::<u16>().ok()?),
+        None => None,
+    };
+    // The `url` crate drops the default port of special schemes
+    match (&scheme, port) {
+        (Scheme::Http, Some(80)) | (Scheme::Https, Some(443)) => return None,
+        _ => {}
+    }
+
+    // Paths from a home directory are split after it
+    if !is_plain_path(path) || path.trim_start_matches('/').starts_with('~') {
+        return None;
+    }
+    let segments = path.split('/').filter(|s| !s.is_empty());
+    let (first, last) = (segments.clone().next()?, segments.clone().next_back()?);
+    // A `.git` suffix before the last segment starts an extra path
+    if segments.clone().rev().skip(1).any(|s| s.ends_with(".git")) {
+        return None;
+    }
+    let count = segments.count();
+    if !scheme_prefix && count < 2 {
+        return None;
+    }
+
+    // Other providers have their own layouts and home directories
+    let provider = Provider::detect(host, port, path);
+    if matches!(provider, Provider::AzureDevOps | Provider::BitbucketServer) {
+        return None;
+    }
+
+    // The default layout, on slices of the path
+    let name = last.trim_end_matches(".git");
+    let (organization, subgroups, owner) = match count {
+        1 => (None, None, name),
+        2 => (None, None, first),
+        _ => {
+            let inner = &path[offset(path, first) + first.len() + 1..offset(path, last) - 1];
+            match inner.rsplit_once('/') {
+                Some((subgroups, owner)) => (Some(first), Some(subgroups), owner),
+                None => (Some(first), None, inner),
+            }
+        }
+    };
+    let fullname = match count {
+        1 => Cow::Owned(format!("{}/{}", name, name)),
+        _ => Cow::Borrowed(&path[offset(path, first)..offset(path, name) + name.len()]),
+    };
+
+    Some(GitUrlRef {
+        input,
+        host: Some(Cow::Borrowed(host)),
+        name: Cow::Borrowed(name),
+        owner: Some(Cow::Borrowed(owner)),
+        subgroups: subgroups.map(Cow::Borrowed),
+        organization: organization.map(Cow::Borrowed),
+        fullname,
+        helper: None,
+        scheme,
+        auth_user: (!auth_user.is_empty()).then_some(Cow::Borrowed(auth_user)),
+        auth_token: auth_token.map(Cow::Borrowed),
+        port,
+        path: Cow::Borrowed(path),
+        path_base: PathBase::Default,
+        git_suffix: path.ends_with(".git"),
+        scheme_prefix,
+        provider,
+        extra: None,
+    })
+}
+
+/// The byte offset of `part`, a slice of `s`, within `s`
+fn offset(s: &str, part: &str) -> usize {
+    part.as_ptr() as usize - s.as_ptr() as usize
+}
+
+/// A non-empty user or token the `url` crate would not percent-encode
+fn is_unreserved(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~'))
+}
+
+/// A lowercase domain name, which isn't an IP address or internationalized
+fn is_plain_host(host: &str) -> bool {
+    let labels_ok = host
+        .split('.')
+        .all(|label| !label.is_empty() && !label.starts_with("xn--"));
+    let last_is_name = host
+        .rsplit('.')
+        .next()
+        .is_some_and(|label| label.starts_with(|c: char| c.is_ascii_lowercase()));
+    labels_ok
+        && last_is_name
+        && host
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'-' | b'.'))
+}
+
+/// A path without empty or dot segments, or characters the `url` crate would
+/// percent-encode
+fn is_plain_path(path: &str) -> bool {
+    let chars_ok = path.bytes().all(|b| {
+        b.is_ascii_alphanumeric()
+            || matches!(
+                b,
+                b'-' | b'.'
+                    | b'_'
+                    | b'~'
+                    | b'!'
+                    | b'$'
+                    | b'&'
+                    | b'\''
+                    | b'('
+                    | b')'
+                    | b'*'
+                    | b'+'
+                    | b','
+                    | b';'
+                    | b'='
+                    | b':'
+                    | b'@'
+                    | b'/'
+            )
+    });
+    let segments_ok = path
+        .trim_start_matches('/')
+        .split('/')
+        .all(|segment| !segment.is_empty() && segment != "." && segment != "..");
+    chars_ok && segments_ok
+}
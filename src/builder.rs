@@ -0,0 +1,406 @@
+use crate::{GitUrl, PathBase, Provider, Scheme};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use thiserror::Error;
+
+/// GitUrlBuilder constructs a [`GitUrl`] from its parts, deriving `path` and
+/// `fullname` and rejecting combinations that can't be expressed as a url
+///
+/// The path follows the layout of the provider, such as Azure DevOps `_git/`
+/// or Bitbucket Server `scm/`, and its parts are percent-encoded like those of
+/// a parsed url, so a `My Project` owner becomes `My%20Project`.
+///
+/// The result is the same as parsing the url it prints, e.g.
+///
+/// ```
+/// use git_url_parse::{GitUrl, Scheme};
+///
+/// let url = GitUrl::builder()
+///     .scheme(Scheme::Ssh)
+///     .scheme_prefix(false)
+///     .auth_user("git")
+///     .host("gitlab.com")
+///     .owner("group")
+///     .subgroups("sub")
+///     .organization("org")
+///     .name("repo")
+///     .git_suffix(true)
+///     .build()
+///     .expect("Invalid url parts");
+///
+/// assert_eq!(url.to_string(), "git@gitlab.com:org/sub/group/repo.git");
+/// assert_eq!(url, GitUrl::parse(&url.to_string()).unwrap());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GitUrlBuilder {
+    scheme: Option<Scheme>,
+    scheme_prefix: Option<bool>,
+    host: Option<String>,
+    port: Option<u16>,
+    organization: Option<String>,
+    subgroups: Option<String>,
+    owner: Option<String>,
+    name: Option<String>,
+    auth_user: Option<String>,
+    auth_token: Option<String>,
+    git_suffix: bool,
+    path_base: PathBase,
+    helper: Option<String>,
+    provider: Option<Provider>,
+}
+
+impl GitUrl {
+    /// Returns a [`GitUrlBuilder`] to construct a `GitUrl` from its parts
+    pub fn builder() -> GitUrlBuilder {
+        GitUrlBuilder::default()
+    }
+}
+
+impl GitUrlBuilder {
+    /// Returns an empty builder, equivalent to [`GitUrl::builder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The url scheme. Defaults to [`Scheme::Https`]
+    pub fn scheme(mut self, scheme: Scheme) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+
+    /// Whether the scheme is printed. Only ssh urls can omit it, which gives
+    /// the scp-like `user@host:path` form. Defaults to `true`
+    pub fn scheme_prefix(mut self, scheme_prefix: bool) -> Self {
+        self.scheme_prefix = Some(scheme_prefix);
+        self
+    }
+
+    /// The fully qualified domain name (FQDN) or IP of the repo. IPv6
+    /// addresses may be given with or without brackets
+    pub fn host(mut self, host: &str) -> Self {
+        let host = host
+            .strip_prefix('[')
+            .and_then(|h| h.strip_suffix(']'))
+            .unwrap_or(host);
+        self.host = Some(host.to_string());
+        self
+    }
+
+    /// The non-conventional port where git service is hosted
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// The organization name, which comes first in the path
+    pub fn organization(mut self, organization: &str) -> Self {
+        self.organization = Some(organization.to_string());
+        self
+    }
+
+    /// Subgroups between the organization and the owner, separated with `/`
+    pub fn subgroups(mut self, subgroups: &str) -> Self {
+        self.subgroups = Some(subgroups.to_string());
+        self
+    }
+
+    /// The owner/account/project name
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.owner = Some(owner.to_string());
+        self
+    }
+
+    /// The name of the repo, without a `.git` suffix
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// The authentication user
+    pub fn auth_user(mut self, user: &str) -> Self {
+        self.auth_user = Some(user.to_string());
+        self
+    }
+
+    /// The password or oauth token. Only http(s) and ftp(s) urls can carry one
+    pub fn auth_token(mut self, token: &str) -> Self {
+        self.auth_token = Some(token.to_string());
+        self
+    }
+
+    /// Whether the path ends with `.git`. Defaults to `false`
+    pub fn git_suffix(mut self, git_suffix: bool) -> Self {
+        self.git_suffix = git_suffix;
+        self
+    }
+
+    /// Whether the path starts from a home directory. Defaults to [`PathBase::Default`]
+    pub fn path_base(mut self, path_base: PathBase) -> Self {
+        self.path_base = path_base;
+        self
+    }
+
+    /// The remote helper, which prefixes the url with `<helper>::`
+    pub fn helper(mut self, helper: &str) -> Self {
+        self.helper = Some(helper.to_string());
+        self
+    }
+
+    /// Override the provider, which is otherwise detected from the other parts
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Returns the `GitUrl` after validating the parts
+    pub fn build(self) -> Result<GitUrl, GitUrlBuildError> {
+        let scheme = self.scheme.unwrap_or(Scheme::Https);
+        let scheme_prefix = self.scheme_prefix.unwrap_or(true);
+
+        let name = self.name.ok_or(GitUrlBuildError::MissingName)?;
+        validate_segment("name", &name)?;
+        if name.ends_with(".git") {
+            return Err(GitUrlBuildError::InvalidComponent("name", name));
+        }
+        if let Some(owner) = &self.owner {
+            validate_segment("owner", owner)?;
+        }
+        if let Some(organization) = &self.organization {
+            validate_segment("organization", organization)?;
+        }
+        if let Some(subgroups) = &self.subgroups {
+            for subgroup in subgroups.split('/') {
+                validate_segment("subgroups", subgroup)?;
+            }
+        }
+        if self.owner.is_none() && (self.organization.is_some() || self.subgroups.is_some()) {
+            return Err(GitUrlBuildError::MissingOwner);
+        }
+        if self.subgroups.is_some() && self.organization.is_none() {
+            return Err(GitUrlBuildError::MissingOrganization);
+        }
+
+        if let Some(host) = &self.host {
+            if host.is_empty() || host.contains(['/', '@', ' ']) {
+                return Err(GitUrlBuildError::InvalidComponent("host", host.clone()));
+            }
+        }
+        if self.port.is_some() && self.host.is_none() {
+            return Err(GitUrlBuildError::PortWithoutHost);
+        }
+        if !scheme_prefix && scheme != Scheme::Ssh {
+            return Err(GitUrlBuildError::SchemeRequired(scheme));
+        }
+
+        match scheme {
+            Scheme::Unspecified => return Err(GitUrlBuildError::UnsupportedScheme(scheme)),
+            Scheme::File | Scheme::GitFile => {
+                if self.host.is_some() {
+                    return Err(GitUrlBuildError::HostNotSupported(scheme));
+                }
+                if self.auth_user.is_some() || self.auth_token.is_some() {
+                    return Err(GitUrlBuildError::AuthNotSupported(scheme));
+                }
+            }
+            _ => {
+                if self.host.is_none() {
+                    return Err(GitUrlBuildError::MissingHost(scheme));
+                }
+                if self.owner.is_none() {
+                    return Err(GitUrlBuildError::MissingOwner);
+                }
+            }
+        }
+        if self.auth_token.is_some()
+            && matches!(
+                scheme.transport(),
+                Scheme::Ssh | Scheme::Git | Scheme::Sftp | Scheme::Rsync
+            )
+        {
+            return Err(GitUrlBuildError::AuthNotSupported(scheme));
+        }
+        if let (Scheme::Ssh, false, Some(port)) = (&scheme, scheme_prefix, self.port) {
+            return Err(GitUrlBuildError::ScpLikeWithPort(port));
+        }
+
+        // Percent-encode the parts the way parsing their url does
+        let encode = |part: Option<String>| part.map(|part| encode_segment(&part));
+        let name = encode_segment(&name);
+        let owner = encode(self.owner);
+        let subgroups = encode(self.subgroups);
+        let organization = encode(self.organization);
+
+        let fullname = [
+            organization.as_deref(),
+            subgroups.as_deref(),
+            owner.as_deref(),
+            Some(name.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<&str>>()
+        .join("/");
+
+        let provider = match (&self.provider, &self.host) {
+            (Some(provider), _) => *provider,
+            (None, Some(host)) => Provider::detect(host, self.port, &fullname),
+            (None, None) => Provider::Generic,
+        };
+
+        // The path in the layout the provider's repos are parsed with
+        let repo_path = match (&self.host, provider) {
+            (Some(host), Provider::AzureDevOps) if self.path_base == PathBase::Default => {
+                azure_devops_path(
+                    &host.to_ascii_lowercase(),
+                    organization.as_deref(),
+                    owner.as_deref(),
+                    subgroups.is_some(),
+                    &name,
+                )
+                .ok_or(GitUrlBuildError::UnsupportedLayout(provider))?
+            }
+            (Some(_), Provider::BitbucketServer) if self.path_base == PathBase::Default => {
+                match scheme.transport() {
+                    Scheme::Http | Scheme::Https => format!("scm/{}", fullname),
+                    _ => fullname.clone(),
+                }
+            }
+            (Some(_), Provider::AzureDevOps | Provider::BitbucketServer) => {
+                return Err(GitUrlBuildError::UnsupportedLayout(provider))
+            }
+            _ => fullname.clone(),
+        };
+
+        let suffix = match self.git_suffix {
+            true => ".git",
+            false => "",
+        };
+        let home = match &self.path_base {
+            PathBase::Default => String::new(),
+            home => format!("{}/", home),
+        };
+        // Normalized ssh urls and home-relative local paths don't keep the leading '/'
+        let path = match (&scheme, &self.path_base) {
+            (Scheme::Ssh, _) | (Scheme::File | Scheme::GitFile, PathBase::Home(_)) => {
+                format!("{}{}{}", home, repo_path, suffix)
+            }
+            _ => format!("/{}{}{}", home, repo_path, suffix),
+        };
+
+        // We're not going to assume anything about metadata from a filepath
+        let (owner, subgroups, organization, fullname) = match &scheme {
+            Scheme::File | Scheme::GitFile => (None, None, None, name.clone()),
+            _ => (owner, subgroups, organization, fullname),
+        };
+
+        Ok(GitUrl {
+            host: self.host.map(|h| h.to_ascii_lowercase()),
+            name,
+            owner,
+            subgroups,
+            organization,
+            fullname,
+            helper: self.helper,
+            scheme,
+            auth_user: self.auth_user,
+            auth_token: self.auth_token,
+            port: self.port,
+            path,
+            path_base: self.path_base,
+            git_suffix: self.git_suffix,
+            scheme_prefix,
+            provider,
+            extra: None,
+            source: None,
+        })
+    }
+}
+
+/// The path of an Azure DevOps repo, as [`AzureDevOpsLayout`] splits it
+///
+/// [`AzureDevOpsLayout`]: crate::AzureDevOpsLayout
+fn azure_devops_path(
+    host: &str,
+    organization: Option<&str>,
+    project: Option<&str>,
+    has_subgroups: bool,
+    name: &str,
+) -> Option<String> {
+    let (organization, project) = match (organization, project, has_subgroups) {
+        (Some(organization), Some(project), false) => (organization, project),
+        _ => return None,
+    };
+
+    match host {
+        "ssh.dev.azure.com" | "vs-ssh.visualstudio.com" => {
+            Some(format!("v3/{}/{}/{}", organization, project, name))
+        }
+        // The organization is the host name, and can't be given in the path
+        _ if host.ends_with(".visualstudio.com") => {
+            match host.strip_suffix(".visualstudio.com") == Some(organization) {
+                true => Some(format!("{}/_git/{}", project, name)),
+                false => None,
+            }
+        }
+        _ => Some(format!("{}/{}/_git/{}", organization, project, name)),
+    }
+}
+
+/// Characters the `url` crate percent-encodes in a path
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+fn encode_segment(s: &str) -> String {
+    utf8_percent_encode(s, PATH_SEGMENT).to_string()
+}
+
+/// A single path segment of the repo path
+fn validate_segment(component: &'static str, value: &str) -> Result<(), GitUrlBuildError> {
+    if value.is_empty()
+        || value == "."
+        || value == ".."
+        || value.contains(['/', '\\', ':', '?', '#', '\0'])
+    {
+        return Err(GitUrlBuildError::InvalidComponent(
+            component,
+            value.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum GitUrlBuildError {
+    #[error("Repo name is required")]
+    MissingName,
+    #[error("An owner is required")]
+    MissingOwner,
+    #[error("Subgroups require an organization")]
+    MissingOrganization,
+    #[error("Scheme {0} requires a host")]
+    MissingHost(Scheme),
+    #[error("Scheme {0} cannot have a host")]
+    HostNotSupported(Scheme),
+    #[error("Port requires a host")]
+    PortWithoutHost,
+    #[error("Scheme {0} cannot carry this auth")]
+    AuthNotSupported(Scheme),
+    #[error("Scheme {0} cannot be omitted")]
+    SchemeRequired(Scheme),
+    #[error("Scheme unsupported: {0}")]
+    UnsupportedScheme(Scheme),
+    #[error("scp-like ssh urls cannot use port {0}")]
+    ScpLikeWithPort(u16),
+    #[error("The parts don't fit the repo paths of {0}")]
+    UnsupportedLayout(Provider),
+    #[error("Invalid {0}: {1:?}")]
+    InvalidComponent(&'static str, String),
+}
@@ -0,0 +1,250 @@
+use crate::GitUrlParseError;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// A single `key = value` of a gitconfig-format file, such as `.git/config`,
+/// `~/.gitconfig` or `.gitmodules`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct ConfigEntry {
+    /// The lowercased section name, e.g. `url`
+    pub section: String,
+    /// The subsection, e.g. the base of `[url "https://example.com/"]`
+    pub subsection: Option<String>,
+    /// The lowercased key name, e.g. `insteadof`
+    pub key: String,
+    /// The value, or `None` for a bare `key` which git reads as `true`
+    pub value: Option<String>,
+    /// The 1-based line of the key, for error messages
+    pub line: usize,
+}
+
+/// Parses gitconfig-format text into its entries, in file order
+///
+/// This follows `git config` syntax: `#` and `;` comments, `[section "sub"]`
+/// and legacy `[section.sub]` headers, quoted values, `\` escapes and line
+/// continuations. Includes are not followed.
+pub(crate) fn parse_config(text: &str) -> Result<Vec<ConfigEntry>, GitUrlParseError> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        pos: 0,
+        line: 1,
+    };
+    let mut entries = Vec::new();
+    let mut section: Option<(String, Option<String>)> = None;
+
+    loop {
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => break,
+            Some('\n') => parser.bump(),
+            Some('#' | ';') => parser.skip_line(),
+            Some('[') => section = Some(parser.section_header()?),
+            Some(c) if c.is_ascii_alphabetic() => {
+                let (name, subsection) = section
+                    .clone()
+                    .ok_or(GitUrlParseError::InvalidConfig(parser.line))?;
+                let line = parser.line;
+                let (key, value) = parser.key_value()?;
+                entries.push(ConfigEntry {
+                    section: name,
+                    subsection,
+                    key,
+                    value,
+                    line,
+                });
+            }
+            Some(_) => return Err(GitUrlParseError::InvalidConfig(parser.line)),
+        }
+    }
+
+    Ok(entries)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) {
+        if self.peek() == Some('\n') {
+            self.line += 1;
+        }
+        self.pos += 1;
+    }
+
+    fn error(&self) -> GitUrlParseError {
+        GitUrlParseError::InvalidConfig(self.line)
+    }
+
+    /// Skips spaces and tabs, but not newlines
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c != '\n' && c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while !matches!(self.peek(), None | Some('\n')) {
+            self.bump();
+        }
+    }
+
+    /// The rest of the line may only hold a comment
+    fn end_of_line(&mut self) -> Result<(), GitUrlParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            None | Some('\n') => Ok(()),
+            Some('#' | ';') => {
+                self.skip_line();
+                Ok(())
+            }
+            Some(_) => Err(self.error()),
+        }
+    }
+
+    /// Parses `[section]`, `[section "subsection"]` or `[section.subsection]`
+    fn section_header(&mut self) -> Result<(String, Option<String>), GitUrlParseError> {
+        self.bump();
+        let mut name = String::new();
+        while let Some(c) = self.peek() {
+            if !(c.is_ascii_alphanumeric() || c == '-' || c == '.') {
+                break;
+            }
+            name.push(c.to_ascii_lowercase());
+            self.bump();
+        }
+        if name.is_empty() {
+            return Err(self.error());
+        }
+
+        let subsection = match self.peek() {
+            Some(']') => {
+                self.bump();
+                // The legacy syntax lowercases the subsection too
+                match name.split_once('.') {
+                    Some((section, sub)) => {
+                        let sub = sub.to_string();
+                        name = section.to_string();
+                        Some(sub)
+                    }
+                    None => None,
+                }
+            }
+            Some(c) if c.is_whitespace() && c != '\n' => {
+                self.skip_whitespace();
+                if self.peek() != Some('"') || name.contains('.') {
+                    return Err(self.error());
+                }
+                self.bump();
+                let mut sub = String::new();
+                loop {
+                    match self.peek() {
+                        None | Some('\n') => return Err(self.error()),
+                        Some('"') => {
+                            self.bump();
+                            break;
+                        }
+                        Some('\\') => {
+                            self.bump();
+                            match self.peek() {
+                                None | Some('\n') => return Err(self.error()),
+                                Some(c) => sub.push(c),
+                            }
+                            self.bump();
+                        }
+                        Some(c) => {
+                            sub.push(c);
+                            self.bump();
+                        }
+                    }
+                }
+                if self.peek() != Some(']') {
+                    return Err(self.error());
+                }
+                self.bump();
+                Some(sub)
+            }
+            _ => return Err(self.error()),
+        };
+
+        // A key may follow the header on the same line
+        self.skip_whitespace();
+        if matches!(self.peek(), Some('#' | ';')) {
+            self.skip_line();
+        }
+        Ok((name, subsection))
+    }
+
+    /// Parses `key`, or `key = value` up to the end of the line
+    fn key_value(&mut self) -> Result<(String, Option<String>), GitUrlParseError> {
+        let mut key = String::new();
+        while let Some(c) = self.peek() {
+            if !(c.is_ascii_alphanumeric() || c == '-') {
+                break;
+            }
+            key.push(c.to_ascii_lowercase());
+            self.bump();
+        }
+
+        self.skip_whitespace();
+        if self.peek() != Some('=') {
+            self.end_of_line()?;
+            return Ok((key, None));
+        }
+        self.bump();
+        self.skip_whitespace();
+
+        let mut value = String::new();
+        // Whitespace is only kept if more of the value follows it
+        let mut pending_whitespace = String::new();
+        let mut quoted = false;
+        loop {
+            let c = match self.peek() {
+                None | Some('\n') if quoted => return Err(self.error()),
+                None | Some('\n') => break,
+                Some('#' | ';') if !quoted => {
+                    self.skip_line();
+                    break;
+                }
+                Some(c) => c,
+            };
+            self.bump();
+
+            match c {
+                '"' => quoted = !quoted,
+                '\\' => {
+                    let escaped = match self.peek() {
+                        Some('\n') => {
+                            self.bump();
+                            continue;
+                        }
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('b') => '\u{8}',
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        _ => return Err(self.error()),
+                    };
+                    self.bump();
+                    value.push_str(&pending_whitespace);
+                    pending_whitespace.clear();
+                    value.push(escaped);
+                }
+                c if c.is_whitespace() && !quoted => pending_whitespace.push(c),
+                c => {
+                    value.push_str(&pending_whitespace);
+                    pending_whitespace.clear();
+                    value.push(c);
+                }
+            }
+        }
+
+        Ok((key, Some(value)))
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{GitUrl, GitUrlParseError, PathBase, Provider, Scheme};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// Re-express the same repository over another transport
+///
+/// Auth that can't be used with the new transport is dropped, the provider's
+/// conventional ssh user and port are applied, and provider-specific path
+/// conventions (such as Azure DevOps `v3/` or Bitbucket Server `scm/`) are
+/// translated. Otherwise the path, including any subgroups and `.git` suffix,
+/// is kept as is.
+impl GitUrl {
+    /// Returns the `https://` url of the repository
+    ///
+    /// Fails for paths from a home directory, such as `git@host:~/repo.git`,
+    /// which are only served over ssh.
+    pub fn to_https(&self) -> Result<GitUrl, GitUrlParseError> {
+        if self.path_base != PathBase::Default && self.provider != Provider::SourceHut {
+            return Err(GitUrlParseError::UnsupportedHomePath);
+        }
+        let host = https_host(self).ok_or(GitUrlParseError::UnsupportedUrlHostFormat)?;
+        let from_http = matches!(self.scheme.transport(), Scheme::Http | Scheme::Https);
+
+        let path = match self.provider {
+            Provider::AzureDevOps => {
+                let (organization, project) = azure_parts(self)?;
+                match host.as_str() {
+                    "dev.azure.com" => {
+                        format!("/{}/{}/_git/{}", organization, project, self.name)
+                    }
+                    _ if host.ends_with(".visualstudio.com") => {
+                        format!("/{}/_git/{}", project, self.name)
+                    }
+                    _ => format!(
+                        "/{}{}/{}/_git/{}",
+                        tfs_prefix(self),
+                        organization,
+                        project,
+                        self.name
+                    ),
+                }
+            }
+            Provider::BitbucketServer => format!("/scm/{}", bitbucket_server_path(self)?),
+            _ => format!("/{}", self.path.trim_start_matches('/')),
+        };
+
+        Ok(GitUrl {
+            host: Some(host),
+            // A remote helper is tied to the transport it was given for
+            helper: self.helper.clone().filter(|_| self.scheme == Scheme::Https),
+            scheme: Scheme::Https,
+            auth_user: self.auth_user.clone().filter(|_| from_http),
+            auth_token: self.auth_token.clone().filter(|_| from_http),
+            port: self.port.filter(|_| from_http),
+            path,
+            scheme_prefix: true,
+            ..self.clone()
+        })
+    }
+
+    /// Returns the `ssh://` url of the repository
+    pub fn to_ssh(&self) -> Result<GitUrl, GitUrlParseError> {
+        let host = self
+            .host
+            .as_deref()
+            .ok_or(GitUrlParseError::UnsupportedUrlHostFormat)?;
+        let from_ssh = self.scheme.transport() == Scheme::Ssh;
+
+        let (host, user, path) = match self.provider {
+            Provider::AzureDevOps => {
+                let (organization, project) = azure_parts(self)?;
+                let v3_path = format!("v3/{}/{}/{}", organization, project, self.name);
+                match host {
+                    "dev.azure.com" | "ssh.dev.azure.com" => (
+                        "ssh.dev.azure.com".to_string(),
+                        Some("git".to_string()),
+                        v3_path,
+                    ),
+                    _ if host.ends_with(".visualstudio.com") => (
+                        "vs-ssh.visualstudio.com".to_string(),
+                        Some(organization.to_string()),
+                        v3_path,
+                    ),
+                    _ => (
+                        host.to_string(),
+                        self.auth_user.clone().filter(|_| from_ssh),
+                        format!(
+                            "{}{}/{}/_git/{}",
+                            tfs_prefix(self),
+                            organization,
+                            project,
+                            self.name
+                        ),
+                    ),
+                }
+            }
+            Provider::BitbucketServer => (
+                host.to_string(),
+                Some("git".to_string()),
+                bitbucket_server_path(self)?,
+            ),
+            // Gerrit accounts log in with their own user name
+            Provider::Gerrit => (
+                host.to_string(),
+                self.auth_user.clone().filter(|_| from_ssh),
+                self.path.trim_start_matches('/').to_string(),
+            ),
+            Provider::Generic => (
+                host.to_string(),
+                match from_ssh {
+                    true => self.auth_user.clone(),
+                    false => Some("git".to_string()),
+                },
+                self.path.trim_start_matches('/').to_string(),
+            ),
+            _ => (
+                ssh_host(host).to_string(),
+                Some("git".to_string()),
+                self.path.trim_start_matches('/').to_string(),
+            ),
+        };
+
+        let port = match (from_ssh, self.port) {
+            (true, Some(port)) => Some(port),
+            _ => self.provider.ssh_port(),
+        };
+
+        Ok(GitUrl {
+            host: Some(host),
+            helper: self.helper.clone().filter(|_| self.scheme == Scheme::Ssh),
+            scheme: Scheme::Ssh,
+            auth_user: user,
+            auth_token: None,
+            port,
+            path,
+            scheme_prefix: true,
+            ..self.clone()
+        })
+    }
+
+    /// Returns the scp-like `user@host:path` url of the repository
+    ///
+    /// Fails for repositories that need a port other than 22, which can't be
+    /// expressed in this form.
+    pub fn to_scp_like(&self) -> Result<GitUrl, GitUrlParseError> {
+        let ssh = self.to_ssh()?;
+        match ssh.port {
+            Some(port) if port != 22 => Err(GitUrlParseError::UnsupportedScpLikePort(port)),
+            _ => Ok(GitUrl {
+                port: None,
+                scheme_prefix: false,
+                ..ssh
+            }),
+        }
+    }
+}
+
+/// Returns the host serving the web interface and https clones for `url`
+pub(crate) fn https_host(url: &GitUrl) -> Option<String> {
+    let host = url.host.as_deref()?;
+    Some(match host {
+        "ssh.github.com" => "github.com".to_string(),
+        "altssh.gitlab.com" => "gitlab.com".to_string(),
+        "altssh.bitbucket.org" => "bitbucket.org".to_string(),
+        "ssh.dev.azure.com" => "dev.azure.com".to_string(),
+        "vs-ssh.visualstudio.com" => format!("{}.visualstudio.com", url.organization.as_deref()?),
+        _ => host.to_string(),
+    })
+}
+
+/// Returns the host serving ssh clones of a public provider at `host`
+fn ssh_host(host: &str) -> &str {
+    match host {
+        "www.github.com" => "github.com",
+        "www.gitlab.com" => "gitlab.com",
+        "www.bitbucket.org" => "bitbucket.org",
+        _ => host,
+    }
+}
+
+/// Returns the organization and project of an Azure DevOps repository
+fn azure_parts(url: &GitUrl) -> Result<(&str, &str), GitUrlParseError> {
+    match &url.organization {
+        Some(organization) => Ok((organization, url.owner.as_deref().unwrap_or(&url.name))),
+        None => Err(GitUrlParseError::UnexpectedFormat),
+    }
+}
+
+/// On-prem TFS serves repositories below `tfs/`
+pub(crate) fn tfs_prefix(url: &GitUrl) -> &'static str {
+    match url.path.trim_start_matches('/').starts_with("tfs/") {
+        true => "tfs/",
+        false => "",
+    }
+}
+
+/// Returns `{key}/{repo}` of a Bitbucket Server repository, with the `.git`
+/// suffix if the url had one
+fn bitbucket_server_path(url: &GitUrl) -> Result<String, GitUrlParseError> {
+    let key = url
+        .owner
+        .as_deref()
+        .ok_or(GitUrlParseError::UnexpectedFormat)?;
+    let suffix = match url.git_suffix {
+        true => ".git",
+        false => "",
+    };
+    Ok(format!("{}/{}{}", key, url.name, suffix))
+}
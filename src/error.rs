@@ -0,0 +1,117 @@
+use crate::source::Components;
+use crate::GitUrlParseError;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::ops::Range;
+use thiserror::Error;
+
+/// ErrorContext is an error from parsing an url with the input it was returned
+/// for, the byte range of its offending component and, where one can be
+/// guessed, the input that was probably meant
+///
+/// ```
+/// use git_url_parse::{GitUrl, GitUrlParseError};
+///
+/// let input = "https://github.com:org/repo.git";
+/// let e = GitUrl::parse(input).map_err(|e| e.with_input(input)).unwrap_err();
+///
+/// assert_eq!(e.error, GitUrlParseError::NonNumericPort("org".to_string()));
+/// assert_eq!(e.component(), "org");
+/// assert_eq!(
+///     e.to_string(),
+///     "port `org` is not numeric, did you mean `github.com:org/repo.git`?"
+/// );
+/// ```
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("{error}{}", suggestion_message(self))]
+pub struct ErrorContext {
+    /// The error, which is more specific than the one returned by the parser
+    /// where the input tells, such as [`GitUrlParseError::NonNumericPort`]
+    pub error: GitUrlParseError,
+    /// The input given to [`GitUrl::parse`](crate::GitUrl::parse)
+    pub input: String,
+    /// The byte range of the offending component of `input`
+    pub span: Range<usize>,
+    /// A corrected input, such as the scp-like form of an url with a path
+    /// where its port should be
+    pub suggestion: Option<String>,
+}
+
+impl ErrorContext {
+    /// Returns the offending component of the input, or `""` if the span is
+    /// not within it
+    pub fn component(&self) -> &str {
+        self.input.get(self.span.clone()).unwrap_or_default()
+    }
+}
+
+impl GitUrlParseError {
+    /// Returns the error with the context of `input`, the url it was returned
+    /// for by [`GitUrl::parse`](crate::GitUrl::parse) or
+    /// [`GitUrl::parse_with`](crate::GitUrl::parse_with)
+    ///
+    /// Parsing returns the plain error so that it stays cheap to match on and
+    /// to discard, and the context is only built for errors that are shown.
+    /// The span is looked up again in `input` and falls back to the whole of
+    /// it, so any input can be given without panicking.
+    pub fn with_input(self, input: &str) -> ErrorContext {
+        let components = Components::of(input);
+        let whole = 0..input.len();
+        let (error, span, suggestion) = match self {
+            GitUrlParseError::UrlParseError(url::ParseError::InvalidPort) => {
+                match components.port {
+                    // A path where the port should be, as in `https://host:org/repo`
+                    Some(port) if !input[port.clone()].chars().all(|c| c.is_ascii_digit()) => (
+                        GitUrlParseError::NonNumericPort(input[port.clone()].to_string()),
+                        port,
+                        Some(input[components.authority_start..].to_string()),
+                    ),
+                    Some(port) => (self, port, None),
+                    None => (self, whole, None),
+                }
+            }
+            GitUrlParseError::UrlParseError(
+                url::ParseError::EmptyHost
+                | url::ParseError::IdnaError
+                | url::ParseError::InvalidIpv4Address
+                | url::ParseError::InvalidIpv6Address
+                | url::ParseError::InvalidDomainCharacter,
+            )
+            | GitUrlParseError::UnsupportedUrlHostFormat => {
+                (self, components.host.unwrap_or(whole), None)
+            }
+            GitUrlParseError::UnexpectedFormat | GitUrlParseError::EmptyPath => {
+                (self, components.path, None)
+            }
+            GitUrlParseError::UnsupportedScheme(_) => {
+                (self, components.scheme.unwrap_or(whole), None)
+            }
+            GitUrlParseError::InvalidUriCharacter(pos) => {
+                match input.get(pos..).and_then(|rest| rest.chars().next()) {
+                    Some(c) => (self, pos..pos + c.len_utf8(), None),
+                    None => (self, whole, None),
+                }
+            }
+            GitUrlParseError::FoundNullBytes => match input.find('\0') {
+                Some(null) => (self, null..null + 1, None),
+                None => (self, whole, None),
+            },
+            error => (error, whole, None),
+        };
+
+        ErrorContext {
+            error,
+            input: input.to_string(),
+            span,
+            suggestion,
+        }
+    }
+}
+
+/// The `, did you mean ..?` after the message of an error with a suggestion
+fn suggestion_message(context: &ErrorContext) -> String {
+    match &context.suggestion {
+        Some(suggestion) => format!(", did you mean `{}`?", suggestion),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,323 @@
+use crate::{GitUrl, GitUrlParseError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use strum::{Display, EnumString, VariantNames};
+
+/// Version control systems the go command can fetch modules from
+#[derive(Debug, PartialEq, Eq, Hash, EnumString, VariantNames, Clone, Display, Copy)]
+pub enum Vcs {
+    #[strum(serialize = "git")]
+    Git,
+    #[strum(serialize = "hg")]
+    Mercurial,
+    #[strum(serialize = "svn")]
+    Subversion,
+    #[strum(serialize = "bzr")]
+    Bazaar,
+    #[strum(serialize = "fossil")]
+    Fossil,
+}
+
+/// GoModule is the repository of a Go import path, such as
+/// `github.com/org/repo/v2/pkg`, resolved the way the go command does
+///
+/// `github.com`, `bitbucket.org`, `gopkg.in` and paths with a vcs suffix, such
+/// as `gitlab.com/group/proj.git/pkg`, are resolved from the path alone. Other
+/// paths are resolved with the `go-import` meta tags of their `?go-get=1` page.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GoModule {
+    /// The import path of the repository root, such as `github.com/org/repo`
+    pub root: String,
+    /// The url of the repository
+    pub repo: GitUrl,
+    /// The version control system of the repository
+    pub vcs: Vcs,
+    /// The directory of the import path within the repository
+    ///
+    /// The major version suffix is not included, since it may name either a
+    /// directory or only the module path of the repository root.
+    pub subdir: Option<String>,
+    /// The major version of a path ending in `/vN`, or `.vN` on `gopkg.in`
+    pub major: Option<u32>,
+}
+
+impl GoModule {
+    /// Returns a `Result<GoModule>` for an `import_path` on a host known to the
+    /// go command, or with a vcs suffix
+    pub fn resolve(import_path: &str) -> Result<GoModule, GitUrlParseError> {
+        let import_path = import_path.trim_matches('/');
+        let elements: Vec<&str> = import_path.split('/').collect();
+
+        let (root_len, repo_url, vcs) = match elements[..] {
+            ["github.com", owner, repo, ..] | ["bitbucket.org", owner, repo, ..] => (
+                3,
+                format!("https://{}/{}/{}", elements[0], owner, repo),
+                Vcs::Git,
+            ),
+            ["gopkg.in", ..] => return gopkg_in(&elements),
+            _ => match vcs_suffix(&elements) {
+                Some((root_len, vcs)) => (
+                    root_len,
+                    format!("https://{}", elements[..root_len].join("/")),
+                    vcs,
+                ),
+                None => return Err(GitUrlParseError::MissingGoImport(import_path.to_string())),
+            },
+        };
+
+        module(
+            elements[..root_len].join("/"),
+            &repo_url,
+            vcs,
+            None,
+            &elements[root_len..],
+        )
+    }
+
+    /// Returns a `Result<GoModule>` using the `go-import` meta tags of `html`,
+    /// the body of the `?go-get=1` page of `import_path`
+    ///
+    /// The first tag whose prefix matches `import_path` is used, preferring
+    /// repositories over `mod` proxies. Without one, `import_path` is resolved
+    /// as with [`GoModule::resolve`].
+    pub fn resolve_with_html(import_path: &str, html: &str) -> Result<GoModule, GitUrlParseError> {
+        let import_path = import_path.trim_matches('/');
+        let imports: Vec<GoImport> = meta_tags(html)
+            .into_iter()
+            .filter(|(name, _)| name == "go-import")
+            .filter_map(|(_, content)| GoImport::parse(&content))
+            .filter(|import| {
+                import_path == import.prefix
+                    || import_path
+                        .strip_prefix(&import.prefix)
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+            .collect();
+
+        let import = imports
+            .iter()
+            .find(|import| import.vcs != "mod")
+            .or_else(|| imports.first());
+        let Some(import) = import else {
+            return GoModule::resolve(import_path);
+        };
+        let vcs = match import.vcs.parse() {
+            Ok(vcs) => vcs,
+            Err(_) => return Err(GitUrlParseError::MissingGoImport(import_path.to_string())),
+        };
+
+        let rest: Vec<&str> = import_path[import.prefix.len()..]
+            .split('/')
+            .filter(|element| !element.is_empty())
+            .collect();
+        module(
+            import.prefix.clone(),
+            &import.repo_root,
+            vcs,
+            import.subdir.as_deref(),
+            &rest,
+        )
+    }
+}
+
+/// Builds a GoModule from the path `elements` below the repository root
+fn module(
+    root: String,
+    repo_url: &str,
+    vcs: Vcs,
+    repo_subdir: Option<&str>,
+    elements: &[&str],
+) -> Result<GoModule, GitUrlParseError> {
+    let (major, elements) = match elements.split_first() {
+        Some((first, rest)) => match major_version(first) {
+            Some(major) => (Some(major), rest),
+            None => (None, elements),
+        },
+        None => (None, elements),
+    };
+
+    let subdir = repo_subdir
+        .into_iter()
+        .chain(elements.iter().copied())
+        .collect::<Vec<&str>>()
+        .join("/");
+
+    Ok(GoModule {
+        root,
+        repo: GitUrl::parse(repo_url)?,
+        vcs,
+        subdir: (!subdir.is_empty()).then_some(subdir),
+        major,
+    })
+}
+
+/// `gopkg.in/pkg.v3` is `github.com/go-pkg/pkg` and `gopkg.in/user/pkg.v3` is
+/// `github.com/user/pkg`
+fn gopkg_in(elements: &[&str]) -> Result<GoModule, GitUrlParseError> {
+    let (root_len, owner, (name, major)) =
+        if let Some(versioned) = elements.get(1).and_then(|e| gopkg_version(e)) {
+            (2, format!("go-{}", versioned.0), versioned)
+        } else if let (Some(owner), Some(versioned)) = (
+            elements.get(1),
+            elements.get(2).and_then(|e| gopkg_version(e)),
+        ) {
+            (3, owner.to_string(), versioned)
+        } else {
+            return Err(GitUrlParseError::MissingGoImport(elements.join("/")));
+        };
+
+    let mut gopkg_module = module(
+        elements[..root_len].join("/"),
+        &format!("https://github.com/{}/{}", owner, name),
+        Vcs::Git,
+        None,
+        &elements[root_len..],
+    )?;
+    gopkg_module.major = Some(major);
+    Ok(gopkg_module)
+}
+
+/// The name and major version of a `pkg.vN` element
+fn gopkg_version(element: &str) -> Option<(&str, u32)> {
+    let (name, version) = element.rsplit_once(".v")?;
+    Some((name, version.parse().ok()?))
+}
+
+/// Returns the number of elements up to and including the one with a vcs
+/// suffix, as in `example.com/repo.git/pkg`
+fn vcs_suffix(elements: &[&str]) -> Option<(usize, Vcs)> {
+    // The first element is the host, which must be a domain name
+    if !elements.first()?.contains('.') {
+        return None;
+    }
+    elements
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find_map(|(i, element)| {
+            let (name, suffix) = element.rsplit_once('.')?;
+            match (name.is_empty(), suffix.parse()) {
+                (false, Ok(vcs)) => Some((i + 1, vcs)),
+                _ => None,
+            }
+        })
+}
+
+/// The major version of a `vN` path element, which starts at 2
+fn major_version(element: &str) -> Option<u32> {
+    let version = element.strip_prefix('v')?;
+    match version.starts_with('0') {
+        true => None,
+        false => version.parse().ok().filter(|major| *major >= 2),
+    }
+}
+
+/// The content of a `go-import` meta tag, `prefix vcs repo-root [subdir]`
+struct GoImport {
+    prefix: String,
+    vcs: String,
+    repo_root: String,
+    subdir: Option<String>,
+}
+
+impl GoImport {
+    fn parse(content: &str) -> Option<GoImport> {
+        let fields: Vec<&str> = content.split_whitespace().collect();
+        let (prefix, vcs, repo_root, subdir) = match fields[..] {
+            [prefix, vcs, repo_root] => (prefix, vcs, repo_root, None),
+            [prefix, vcs, repo_root, subdir] => (prefix, vcs, repo_root, Some(subdir)),
+            _ => return None,
+        };
+        Some(GoImport {
+            prefix: prefix.trim_end_matches('/').to_string(),
+            vcs: vcs.to_string(),
+            repo_root: repo_root.to_string(),
+            subdir: subdir.map(|s| s.trim_matches('/').to_string()),
+        })
+    }
+}
+
+/// Returns the name and content of the `<meta>` tags of an html page, which
+/// the go command reads until the end of `<head>`
+fn meta_tags(html: &str) -> Vec<(String, String)> {
+    // Lowercasing ascii keeps the byte offsets of `html`
+    let lower = html.to_ascii_lowercase();
+    let end = ["</head", "<body"]
+        .iter()
+        .filter_map(|tag| lower.find(tag))
+        .min()
+        .unwrap_or(html.len());
+
+    let mut tags = Vec::new();
+    let mut pos = 0;
+    while let Some(start) = lower[pos..end].find("<meta") {
+        let start = pos + start + "<meta".len();
+        let close = lower[start..].find('>').map_or(html.len(), |i| start + i);
+        let attrs = attributes(&html[start..close]);
+        pos = close.min(end);
+
+        let attr = |name: &str| {
+            attrs
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+        };
+        if let (Some(name), Some(content)) = (attr("name"), attr("content")) {
+            tags.push((name, content));
+        }
+    }
+    tags
+}
+
+/// Splits the attributes of a tag into lowercase names and unescaped values
+fn attributes(tag: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut rest = tag.trim_start();
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c == '=' || c == '/' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            Some(value) => {
+                let value = value.trim_start();
+                let (value, after) = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let value = &value[1..];
+                        let end = value.find(quote).unwrap_or(value.len());
+                        (&value[..end], value.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = value.find(char::is_whitespace).unwrap_or(value.len());
+                        (&value[..end], &value[end..])
+                    }
+                };
+                rest = after;
+                unescape(value)
+            }
+            None => {
+                // A `/` closing the tag, or an attribute without a value
+                rest = rest.strip_prefix('/').unwrap_or(rest);
+                String::new()
+            }
+        };
+        if !name.is_empty() {
+            attrs.push((name, value));
+        }
+        rest = rest.trim_start();
+    }
+    attrs
+}
+
+/// Replaces the character references that may appear in urls
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
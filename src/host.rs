@@ -0,0 +1,119 @@
+use crate::{GitUrl, GitUrlParseError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::net::{Ipv4Addr, Ipv6Addr};
+use core::str::FromStr;
+
+/// The host of a url, as a domain name or an IP address
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Host {
+    /// A domain name, such as `github.com`
+    Domain(String),
+    /// An IPv4 address, such as `192.0.2.1`
+    Ipv4(Ipv4Addr),
+    /// An IPv6 address, with the zone id of a link-local address, such as
+    /// `eth0` in `[fe80::1%eth0]`
+    Ipv6(Ipv6Addr, Option<String>),
+}
+
+/// IPv6 addresses are written in brackets, with a raw `%` before the zone id
+/// as git and ssh expect it
+impl fmt::Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Host::Domain(domain) => write!(f, "{}", domain),
+            Host::Ipv4(addr) => write!(f, "{}", addr),
+            Host::Ipv6(addr, None) => write!(f, "[{}]", addr),
+            Host::Ipv6(addr, Some(zone)) => write!(f, "[{}%{}]", addr, zone),
+        }
+    }
+}
+
+/// Parses a host with or without the brackets around an IPv6 address. The zone
+/// id may be separated with `%` or the url-encoded `%25`.
+impl FromStr for Host {
+    type Err = GitUrlParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(GitUrlParseError::UnsupportedUrlHostFormat);
+        }
+        let unbracketed = s
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .unwrap_or(s);
+        if !unbracketed.contains(':') {
+            return Ok(match unbracketed.parse() {
+                Ok(addr) => Host::Ipv4(addr),
+                Err(_) => Host::Domain(unbracketed.to_string()),
+            });
+        }
+
+        let (addr, zone) = match unbracketed.split_once('%') {
+            Some((addr, zone)) => (addr, Some(decode_zone(zone).to_string())),
+            None => (unbracketed, None),
+        };
+        match addr.parse() {
+            Ok(addr) if zone.as_deref() != Some("") => Ok(Host::Ipv6(addr, zone)),
+            _ => Err(GitUrlParseError::UnsupportedUrlHostFormat),
+        }
+    }
+}
+
+impl GitUrl {
+    /// Returns the `host` as a domain name or IP address
+    pub fn typed_host(&self) -> Option<Host> {
+        self.host.as_deref()?.parse().ok()
+    }
+}
+
+/// A zone id written after `%25`, its url-encoded separator, or after a raw `%`
+fn decode_zone(zone: &str) -> &str {
+    match zone.strip_prefix("25") {
+        Some(decoded) if !decoded.is_empty() => decoded,
+        _ => zone,
+    }
+}
+
+/// Removes the zone id from a bracketed IPv6 host of `url`, which the `url`
+/// crate rejects, and returns it separately
+pub(crate) fn split_zone(url: &str) -> (String, Option<String>) {
+    let Some((start, end)) = bracketed_host(url) else {
+        return (url.to_string(), None);
+    };
+    match url[start..end].find('%') {
+        Some(percent) => (
+            format!("{}{}", &url[..start + percent], &url[end..]),
+            Some(decode_zone(&url[start + percent + 1..end]).to_string()),
+        ),
+        None => (url.to_string(), None),
+    }
+}
+
+/// Replaces the contents of a bracketed IPv6 host with `x`, so the `:`s of the
+/// address aren't taken for separators
+pub(crate) fn mask_ipv6(url: &str) -> String {
+    match bracketed_host(url) {
+        Some((start, end)) => format!(
+            "{}{}{}",
+            &url[..start],
+            "x".repeat(end - start),
+            &url[end..]
+        ),
+        None => url.to_string(),
+    }
+}
+
+/// Returns the byte range between the brackets of an IPv6 host, if `url` has one
+/// before its path
+fn bracketed_host(url: &str) -> Option<(usize, usize)> {
+    let authority_start = url.find("://").map_or(0, |i| i + 3);
+    let open = authority_start + url[authority_start..].find('[')?;
+    let close = open + url[open..].find(']')?;
+    // The brackets must come before the path
+    match url[authority_start..open].contains('/') {
+        true => None,
+        false => Some((open + 1, close)),
+    }
+}
@@ -0,0 +1,150 @@
+use crate::convert::https_host;
+use crate::{GitUrl, PathBase, Provider, Scheme};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::Hasher;
+
+/// RepoId identifies a repository independently of how its url is written
+///
+/// The scheme, auth, default ports, `.git` suffix and trailing slashes are
+/// ignored, ssh-only hosts such as `ssh.dev.azure.com` are mapped to the host of
+/// the web interface, and the path is reduced to the repo's full name, so all
+/// urls that clone the same repository have the same `RepoId`. Paths on hosts
+/// that treat them case-insensitively (GitHub, GitLab, Bitbucket, Azure DevOps
+/// and Gitea) are lowercased.
+///
+/// Non-default ports are kept, since they may select a different server.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RepoId {
+    /// The lowercased host, or `None` for local repositories
+    pub host: Option<String>,
+    /// The port, if it isn't the default of the scheme or provider
+    pub port: Option<u16>,
+    /// The canonical segments of the repo's full name or local path
+    pub segments: Vec<String>,
+}
+
+impl RepoId {
+    /// Returns a hash of the `RepoId` that is stable across processes,
+    /// platforms and versions of this crate, for use as a cache key
+    ///
+    /// This is the 64-bit FNV-1a hash of the `Display` form of the `RepoId`.
+    pub fn stable_hash(&self) -> u64 {
+        let mut hasher = Fnv1aHasher::default();
+        hasher.write(self.to_string().as_bytes());
+        hasher.finish()
+    }
+}
+
+/// The 64-bit FNV-1a hash, which doesn't need std and doesn't depend on the
+/// platform for the bytes it is given
+pub(crate) struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Fnv1aHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+/// Formats as `host[:port]/segments...`, or `/segments...` for local repositories
+impl fmt::Display for RepoId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(host) = &self.host {
+            write!(f, "{}", host)?;
+        }
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        write!(f, "/{}", self.segments.join("/"))
+    }
+}
+
+impl GitUrl {
+    /// Returns the [`RepoId`] of the repository this url points to
+    pub fn repo_id(&self) -> RepoId {
+        if self.scheme.transport() == Scheme::File || self.host.is_none() {
+            return RepoId {
+                host: None,
+                port: None,
+                segments: self
+                    .path
+                    .trim_end_matches(".git")
+                    .split(['/', '\\'])
+                    .filter(|s| !s.is_empty() && *s != ".")
+                    .map(|s| s.to_string())
+                    .collect(),
+            };
+        }
+
+        let web_host = https_host(self);
+        // Ssh endpoints on another host, e.g. ssh.github.com:443, use their own port
+        let port = match web_host == self.host {
+            true => self.port.filter(|port| {
+                Some(*port) != self.scheme.default_port() && Some(*port) != self.provider.ssh_port()
+            }),
+            false => None,
+        };
+        let host = web_host.map(|host| canonical_host(&host.to_ascii_lowercase()));
+
+        let case_insensitive = matches!(
+            self.provider,
+            Provider::GitHub
+                | Provider::GitLab
+                | Provider::BitbucketCloud
+                | Provider::BitbucketServer
+                | Provider::AzureDevOps
+                | Provider::Gitea
+        );
+        // Repos in different home directories are different repos
+        let home = match &self.path_base {
+            PathBase::Default => None,
+            home => Some(home.to_string()),
+        };
+        let segments = home
+            .into_iter()
+            .chain(self.fullname.split('/').filter(|s| !s.is_empty()).map(
+                |s| match case_insensitive {
+                    true => s.to_lowercase(),
+                    false => s.to_string(),
+                },
+            ))
+            .collect();
+
+        RepoId {
+            host,
+            port,
+            segments,
+        }
+    }
+
+    /// Returns whether `self` and `other` point to the same repository,
+    /// regardless of how their urls are written. See [`RepoId`].
+    pub fn same_repo(&self, other: &GitUrl) -> bool {
+        self.repo_id() == other.repo_id()
+    }
+}
+
+/// Collapses aliases of public hosts
+fn canonical_host(host: &str) -> String {
+    match host.strip_prefix("www.") {
+        Some(host) => host.to_string(),
+        None if host.ends_with(".visualstudio.com") => "dev.azure.com".to_string(),
+        None => host.to_string(),
+    }
+}
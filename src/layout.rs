@@ -0,0 +1,240 @@
+use crate::GitUrlParseError;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The repository metadata a [`HostLayout`] extracts from the path of a url
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RepoPath {
+    /// The owner/account/project name
+    pub owner: Option<String>,
+    /// Subgroups between the owner and the repo, joined with `/`
+    pub subgroups: Option<String>,
+    /// The organization name
+    pub organization: Option<String>,
+    /// The name of the repo, without a `.git` suffix
+    pub name: String,
+    /// Trailing path after the repo that is not part of its identity, joined with `/`
+    pub extra: Option<String>,
+}
+
+impl RepoPath {
+    /// The full name of the repo, formatted as "organization/subgroups/owner/name"
+    /// with missing components left out
+    pub fn fullname(&self) -> String {
+        [
+            self.organization.as_deref(),
+            self.subgroups.as_deref(),
+            self.owner.as_deref(),
+            Some(self.name.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<&str>>()
+        .join("/")
+    }
+}
+
+/// Describes how a git host arranges repositories in its url paths
+///
+/// Implementations receive the non-empty `/`-separated segments of the path in
+/// order, with any `.git` suffix still attached, and return the repository
+/// metadata. Register a layout for a host with [`HostRegistry::register_layout`].
+///
+/// [`HostRegistry::register_layout`]: crate::HostRegistry::register_layout
+pub trait HostLayout: fmt::Debug + Send + Sync {
+    /// Split the path `segments` into repository metadata
+    fn split(&self, segments: &[&str]) -> Result<RepoPath, GitUrlParseError>;
+}
+
+/// The layout used when nothing more specific is known about a host
+///
+/// * `owner/name`
+/// * `organization/owner/name`
+/// * `organization/subgroups.../owner/name`
+///
+/// A single segment is used as both owner and name. The repo ends at the first
+/// segment with a `.git` suffix, and the segments after it, such as the
+/// `info/refs` of `org/repo.git/info/refs`, are the extra path.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultLayout;
+
+impl HostLayout for DefaultLayout {
+    fn split(&self, segments: &[&str]) -> Result<RepoPath, GitUrlParseError> {
+        let (segments, extra) = match segments.iter().position(|s| s.ends_with(".git")) {
+            Some(end) => (&segments[..=end], join_extra(&segments[end + 1..])),
+            None => (segments, None),
+        };
+        let (name, rest) = match segments.split_last() {
+            Some((name, rest)) => (repo_name(name), rest),
+            None => return Err(GitUrlParseError::UnexpectedFormat),
+        };
+
+        Ok(match rest {
+            [] => RepoPath {
+                owner: Some(name.clone()),
+                name,
+                extra,
+                ..RepoPath::default()
+            },
+            [owner] => RepoPath {
+                owner: Some(owner.to_string()),
+                name,
+                extra,
+                ..RepoPath::default()
+            },
+            [organization, subgroups @ .., owner] => RepoPath {
+                owner: Some(owner.to_string()),
+                subgroups: match subgroups.is_empty() {
+                    true => None,
+                    false => Some(subgroups.join("/")),
+                },
+                organization: Some(organization.to_string()),
+                name,
+                extra,
+            },
+        })
+    }
+}
+
+/// A layout for hosts that serve repos below a fixed path prefix, such as
+/// Bitbucket Server's `/scm/` or GitLab installed at a relative url root
+///
+/// The prefix is removed when present and the remainder, including any extra
+/// path, is split with [`DefaultLayout`].
+#[derive(Debug, Clone)]
+pub struct PrefixLayout {
+    prefix: Vec<String>,
+}
+
+impl PrefixLayout {
+    /// `prefix` may contain several segments, such as `"gitlab/scm"`
+    pub fn new(prefix: &str) -> Self {
+        PrefixLayout {
+            prefix: prefix
+                .split('/')
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl HostLayout for PrefixLayout {
+    fn split(&self, segments: &[&str]) -> Result<RepoPath, GitUrlParseError> {
+        let has_prefix = segments.len() > self.prefix.len()
+            && self
+                .prefix
+                .iter()
+                .zip(segments)
+                .all(|(p, s)| p.eq_ignore_ascii_case(s));
+
+        match has_prefix {
+            true => DefaultLayout.split(&segments[self.prefix.len()..]),
+            false => DefaultLayout.split(segments),
+        }
+    }
+}
+
+/// The layout of Azure DevOps and on-prem TFS / Azure DevOps Server repos
+///
+/// * `{organization}/{project}/_git/{repo}` on dev.azure.com
+/// * `[DefaultCollection/]{project}/_git/{repo}` on `{organization}.visualstudio.com`
+/// * `[tfs/]{collection}/{project}/_git/{repo}` on premises, where the collection
+///   is reported as the organization
+/// * `v3/{organization}/{project}/{repo}` over ssh
+///
+/// When the project is omitted (`_git/{repo}`), it has the same name as the repo.
+/// Segments after the repo are the extra path.
+#[derive(Debug, Default, Clone)]
+pub struct AzureDevOpsLayout {
+    organization: Option<String>,
+}
+
+impl AzureDevOpsLayout {
+    /// A layout for hosts where the path does not start with the organization,
+    /// such as `{organization}.visualstudio.com`
+    pub fn with_organization(organization: &str) -> Self {
+        AzureDevOpsLayout {
+            organization: Some(organization.to_string()),
+        }
+    }
+}
+
+impl HostLayout for AzureDevOpsLayout {
+    fn split(&self, segments: &[&str]) -> Result<RepoPath, GitUrlParseError> {
+        if let [version, rest @ ..] = segments {
+            if version.eq_ignore_ascii_case("v3") {
+                return match rest {
+                    [organization, project, repo, extra @ ..] => Ok(RepoPath {
+                        owner: Some(project.to_string()),
+                        organization: Some(organization.to_string()),
+                        name: repo_name(repo),
+                        extra: join_extra(extra),
+                        ..RepoPath::default()
+                    }),
+                    _ => Err(GitUrlParseError::UnexpectedFormat),
+                };
+            }
+        }
+
+        let git_pos = match segments
+            .iter()
+            .position(|s| s.eq_ignore_ascii_case("_git") || s.eq_ignore_ascii_case("_ssh"))
+        {
+            Some(pos) => pos,
+            None => return DefaultLayout.split(segments),
+        };
+
+        let name = match segments.get(git_pos + 1) {
+            Some(repo) => repo_name(repo),
+            None => return Err(GitUrlParseError::UnexpectedFormat),
+        };
+        let extra = join_extra(&segments[git_pos + 2..]);
+
+        let mut prefix = &segments[..git_pos];
+        if let [tfs, rest @ ..] = prefix {
+            if tfs.eq_ignore_ascii_case("tfs") {
+                prefix = rest;
+            }
+        }
+
+        let (organization, rest) = match (&self.organization, prefix) {
+            (Some(organization), [collection, rest @ ..])
+                if collection.eq_ignore_ascii_case("DefaultCollection") =>
+            {
+                (organization.clone(), rest)
+            }
+            (Some(organization), rest) => (organization.clone(), rest),
+            (None, [organization, rest @ ..]) => (organization.to_string(), rest),
+            (None, []) => return Err(GitUrlParseError::UnexpectedFormat),
+        };
+
+        let project = match rest {
+            [] => name.clone(),
+            [project] => project.to_string(),
+            _ => return Err(GitUrlParseError::UnexpectedFormat),
+        };
+
+        Ok(RepoPath {
+            owner: Some(project),
+            subgroups: None,
+            organization: Some(organization),
+            name,
+            extra,
+        })
+    }
+}
+
+/// Joins the segments after the repo, if there are any
+fn join_extra(segments: &[&str]) -> Option<String> {
+    match segments.is_empty() {
+        true => None,
+        false => Some(segments.join("/")),
+    }
+}
+
+/// Returns the repo name of a path segment, without the `.git` suffix
+pub(crate) fn repo_name(segment: &str) -> String {
+    segment.trim_end_matches(".git").to_string()
+}
@@ -1,17 +1,66 @@
-use std::fmt;
-use std::str::FromStr;
-use strum::{Display, EnumString, VariantNames};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+use strum::{Display, EnumString, IntoStaticStr, VariantNames};
 use thiserror::Error;
 use url::Url;
 
+mod borrowed;
+mod builder;
+mod config;
+mod convert;
+mod error;
+mod go_module;
+mod host;
+mod identity;
+mod layout;
+mod location;
+mod npm;
+mod options;
+mod provider;
 #[cfg(feature = "python")]
 mod python;
+mod rewrite;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub mod serde_str;
+mod source;
+mod ssh_config;
+mod submodule;
+mod terraform;
+mod validation;
+mod web;
+
+pub use borrowed::GitUrlRef;
+pub use builder::{GitUrlBuildError, GitUrlBuilder};
+pub use error::ErrorContext;
+pub use go_module::{GoModule, Vcs};
+pub use host::Host;
+pub use identity::RepoId;
+pub use layout::{AzureDevOpsLayout, DefaultLayout, HostLayout, PrefixLayout, RepoPath};
+pub use location::{LineRange, LocationKind, RepoLocation};
+pub use npm::NpmSpec;
+pub use options::ParseOptions;
+pub use provider::{HostRegistry, Provider};
+pub use rewrite::{RemoteUrls, RewriteRule, UrlRewriter};
+pub use source::{UrlSource, UrlStyle};
+pub use ssh_config::{ResolvedSshUrl, SshConfig, SshHost};
+pub use submodule::Submodule;
+pub use terraform::TerraformSource;
+pub use validation::{NameComponent, ProviderRules, Violation, ViolationKind};
 
 #[cfg(feature = "tracing")]
 use tracing::debug;
 
 /// Supported uri schemes for parsing
-#[derive(Debug, PartialEq, Eq, EnumString, VariantNames, Clone, Display, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, EnumString, VariantNames, Clone, Display)]
 #[strum(serialize_all = "kebab_case")]
 pub enum Scheme {
     /// Represents `file://` url scheme
@@ -22,6 +71,15 @@ pub enum Scheme {
     Ftps,
     /// Represents `git://` url scheme
     Git,
+    /// Represents `git+file://` url scheme
+    #[strum(serialize = "git+file")]
+    GitFile,
+    /// Represents `git+http://` url scheme
+    #[strum(serialize = "git+http")]
+    GitHttp,
+    /// Represents `git+https://` url scheme
+    #[strum(serialize = "git+https")]
+    GitHttps,
     /// Represents `git+ssh://` url scheme
     #[strum(serialize = "git+ssh")]
     GitSsh,
@@ -29,17 +87,76 @@ pub enum Scheme {
     Http,
     /// Represents `https://` url scheme
     Https,
+    /// Represents `rsync://` url scheme
+    Rsync,
+    /// Represents `sftp://` url scheme
+    Sftp,
     /// Represents `ssh://` url scheme
     Ssh,
     /// Represents No url scheme
     Unspecified,
+    /// Represents any other url scheme, such as `s3://` or the `us-east-1://`
+    /// of AWS CodeCommit
+    #[strum(default)]
+    Other(String),
+}
+
+impl Scheme {
+    /// Returns the port used by the scheme when none is given
+    pub fn default_port(&self) -> Option<u16> {
+        match self {
+            Scheme::Ftp => Some(21),
+            Scheme::Ftps => Some(990),
+            Scheme::Git => Some(9418),
+            Scheme::GitHttp | Scheme::Http => Some(80),
+            Scheme::GitHttps | Scheme::Https => Some(443),
+            Scheme::Rsync => Some(873),
+            Scheme::GitSsh | Scheme::Sftp | Scheme::Ssh => Some(22),
+            Scheme::File | Scheme::GitFile | Scheme::Unspecified | Scheme::Other(_) => None,
+        }
+    }
+
+    /// Returns the transport git uses for the scheme, which drops the `git+`
+    /// prefix package managers add, e.g. `git+https` is [`Scheme::Https`]
+    pub fn transport(&self) -> Scheme {
+        match self {
+            Scheme::GitFile => Scheme::File,
+            Scheme::GitHttp => Scheme::Http,
+            Scheme::GitHttps => Scheme::Https,
+            Scheme::GitSsh => Scheme::Ssh,
+            scheme => scheme.clone(),
+        }
+    }
+}
+
+/// Where the path of a url starts from
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub enum PathBase {
+    /// The path is used as written
+    #[default]
+    Default,
+    /// The path starts with `~/`, or `~user/` for another user's home
+    /// directory, as in `git@host:~/repo.git` or `ssh://host/~user/repo.git`
+    Home(Option<String>),
+}
+
+/// Formats as the `~` or `~user` that starts the path, if any
+impl fmt::Display for PathBase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PathBase::Default => Ok(()),
+            PathBase::Home(None) => write!(f, "~"),
+            PathBase::Home(Some(user)) => write!(f, "~{}", user),
+        }
+    }
 }
 
 /// GitUrl represents an input url that is a url used by git
 /// Internally during parsing the url is sanitized and uses the `url` crate to perform
 /// the majority of the parsing effort, and with some extra handling to expose
 /// metadata used my many git hosting services
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GitUrl {
     /// The fully qualified domain name (FQDN) or IP of the repo
     pub host: Option<String>,
@@ -53,6 +170,9 @@ pub struct GitUrl {
     pub organization: Option<String>,
     /// The full name of the repo, formatted as "owner/name"
     pub fullname: String,
+    /// The remote helper of a `<transport>::<address>` url, such as
+    /// `persistent-https` in `persistent-https::https://host/repo.git`
+    pub helper: Option<String>,
     /// The git url scheme
     pub scheme: Scheme,
     /// The authentication user
@@ -63,64 +183,75 @@ pub struct GitUrl {
     pub port: Option<u16>,
     /// The path to repo w/ respect to user + hostname
     pub path: String,
+    /// Whether the path is relative to a home directory
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub path_base: PathBase,
     /// Indicate if url uses the .git suffix
     pub git_suffix: bool,
     /// Indicate if url explicitly uses its scheme
     pub scheme_prefix: bool,
+    /// The git hosting service detected from the host and path
+    pub provider: Provider,
+    /// Trailing path after the repo that is not part of its identity, such as
+    /// the `info/refs` of `org/repo.git/info/refs`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub extra: Option<String>,
+    /// The input the url was parsed from, which is not compared by `==`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub source: Option<UrlSource>,
+}
 
-    /// How many leading parts of the path should be skipped.
-    pub _skip_part_count: usize,
+/// Urls are equal when their parts are, whatever syntax they were written in
+impl PartialEq for GitUrl {
+    fn eq(&self, other: &GitUrl) -> bool {
+        self.host == other.host
+            && self.name == other.name
+            && self.owner == other.owner
+            && self.subgroups == other.subgroups
+            && self.organization == other.organization
+            && self.fullname == other.fullname
+            && self.helper == other.helper
+            && self.scheme == other.scheme
+            && self.auth_user == other.auth_user
+            && self.auth_token == other.auth_token
+            && self.port == other.port
+            && self.path == other.path
+            && self.path_base == other.path_base
+            && self.git_suffix == other.git_suffix
+            && self.scheme_prefix == other.scheme_prefix
+            && self.provider == other.provider
+            && self.extra == other.extra
+    }
 }
 
 /// Build the printable GitUrl from its components
+///
+/// A parsed url is written as its input while the parts it writes are
+/// unchanged. Otherwise it is written like [`GitUrl::to_canonical_string`], in
+/// the style of the input for `git:host/path` urls and Windows paths.
 impl fmt::Display for GitUrl {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let scheme_prefix = match self.scheme_prefix {
-            true => format!("{}://", self.scheme),
-            false => String::new(),
+        let source = match &self.source {
+            Some(source) => source,
+            None => return write!(f, "{}", self.to_canonical_string()),
         };
+        if let Some(input) = source.input_of(self) {
+            return write!(f, "{}", input);
+        }
 
-        let auth_info = match self.scheme {
-            Scheme::Ssh | Scheme::Git | Scheme::GitSsh => {
-                if let Some(user) = &self.auth_user {
-                    format!("{}@", user)
-                } else {
-                    String::new()
-                }
-            }
-            Scheme::Http | Scheme::Https => match (&self.auth_user, &self.auth_token) {
-                (Some(user), Some(token)) => format!("{}:{}@", user, token),
-                (Some(user), None) => format!("{}@", user),
-                (None, Some(token)) => format!("{}@", token),
-                (None, None) => String::new(),
+        let canonical = self.to_canonical_string();
+        let helper = self.helper.as_ref().map_or(0, |helper| helper.len() + 2);
+        let (helper, address) = canonical.split_at(helper);
+        match (source.style, &self.scheme) {
+            (UrlStyle::ShortGit, Scheme::Git) => match address.strip_prefix("git://") {
+                Some(rest) => write!(f, "{}git:{}", helper, rest),
+                None => write!(f, "{}", canonical),
             },
-            _ => String::new(),
-        };
-
-        let host = match &self.host {
-            Some(host) => host.to_string(),
-            None => String::new(),
-        };
-
-        let port = match &self.port {
-            Some(p) => format!(":{}", p),
-            None => String::new(),
-        };
-
-        let path = match &self.scheme {
-            Scheme::Ssh => {
-                if self.port.is_some() {
-                    format!("/{}", &self.path)
-                } else {
-                    format!(":{}", &self.path)
-                }
+            (UrlStyle::WindowsPath, Scheme::File) if !self.scheme_prefix => {
+                write!(f, "{}{}", helper, address.replace('/', "\\"))
             }
-            _ => self.path.to_string(),
-        };
-
-        let git_url_str = format!("{}{}{}{}{}", scheme_prefix, auth_info, host, port, path);
-
-        write!(f, "{}", git_url_str)
+            _ => write!(f, "{}", canonical),
+        }
     }
 }
 
@@ -133,14 +264,18 @@ impl Default for GitUrl {
             subgroups: None,
             organization: None,
             fullname: "".to_string(),
+            helper: None,
             scheme: Scheme::Unspecified,
             auth_user: None,
             auth_token: None,
             port: None,
             path: "".to_string(),
+            path_base: PathBase::Default,
             git_suffix: false,
             scheme_prefix: false,
-            _skip_part_count: 0,
+            provider: Provider::Generic,
+            extra: None,
+            source: None,
         }
     }
 }
@@ -163,18 +298,128 @@ impl GitUrl {
         new_giturl
     }
 
-    pub fn parse_with_skips(url: &str, skip_part_count: usize) -> Result<GitUrl, GitUrlParseError> {
+    /// Returns the url written from its parts, in the canonical form of its
+    /// scheme, without the style details of the input it was parsed from
+    pub fn to_canonical_string(&self) -> String {
+        let scheme_prefix = match self.scheme_prefix {
+            true => format!("{}://", self.scheme),
+            false => String::new(),
+        };
+
+        let auth_info = match self.scheme.transport() {
+            Scheme::Ssh | Scheme::Git | Scheme::Sftp | Scheme::Rsync => {
+                if let Some(user) = &self.auth_user {
+                    format!("{}@", user)
+                } else {
+                    String::new()
+                }
+            }
+            Scheme::Http | Scheme::Https | Scheme::Ftp | Scheme::Ftps => {
+                match (&self.auth_user, &self.auth_token) {
+                    (Some(user), Some(token)) => format!("{}:{}@", user, token),
+                    (Some(user), None) => format!("{}@", user),
+                    (None, Some(token)) => format!("{}@", token),
+                    (None, None) => String::new(),
+                }
+            }
+            _ => String::new(),
+        };
+
+        // IPv6 hosts are written in brackets
+        let host = match &self.host {
+            Some(host) if host.contains(':') => format!("[{}]", host),
+            Some(host) => host.to_string(),
+            None => String::new(),
+        };
+
+        let port = match &self.port {
+            Some(p) => format!(":{}", p),
+            None => String::new(),
+        };
+
+        let path = match &self.scheme {
+            Scheme::Ssh => {
+                if self.port.is_some() || self.scheme_prefix {
+                    format!("/{}", &self.path)
+                } else {
+                    format!(":{}", &self.path)
+                }
+            }
+            _ => self.path.to_string(),
+        };
+
+        let helper = match &self.helper {
+            Some(helper) => format!("{}::", helper),
+            None => String::new(),
+        };
+
+        format!(
+            "{}{}{}{}{}{}",
+            helper, scheme_prefix, auth_info, host, port, path
+        )
+    }
+
+    /// Returns a `Result<GitUrl>` like [`GitUrl::parse`], but consults `registry`
+    /// for the `provider` field and the layout of the repo path before falling back
+    /// to [`Provider::detect`] and [`Provider::default_layout`]
+    pub fn parse_with_registry(
+        url: &str,
+        registry: &HostRegistry,
+    ) -> Result<GitUrl, GitUrlParseError> {
+        let parsed = GitUrl::parse_parts(url, registry)?;
+
+        Ok(GitUrl {
+            source: Some(UrlSource::new(url, &parsed)),
+            ..parsed
+        })
+    }
+
+    /// Parses an url, which may have a remote helper, without recording its source
+    pub(crate) fn parse_parts(
+        url: &str,
+        registry: &HostRegistry,
+    ) -> Result<GitUrl, GitUrlParseError> {
+        // Remote helper urls wrap the address the helper is given
+        Ok(match split_helper(url) {
+            Some((helper, address)) => {
+                #[cfg(feature = "tracing")]
+                debug!("Found remote helper {:?} for address {:?}", helper, address);
+
+                // Helpers don't nest
+                let inner = match split_helper(address) {
+                    Some(_) => None,
+                    None => GitUrl::parse_url(address, registry).ok(),
+                };
+                match inner {
+                    Some(inner) => GitUrl {
+                        helper: Some(helper.to_string()),
+                        ..inner
+                    },
+                    // The address is only meaningful to the helper
+                    None => {
+                        let name = helper_repo_name(address);
+                        GitUrl {
+                            fullname: name.clone(),
+                            name,
+                            helper: Some(helper.to_string()),
+                            path: address.to_string(),
+                            ..GitUrl::default()
+                        }
+                    }
+                }
+            }
+            None => GitUrl::parse_url(url, registry)?,
+        })
+    }
+
+    /// Parses an url without a remote helper
+    fn parse_url(url: &str, registry: &HostRegistry) -> Result<GitUrl, GitUrlParseError> {
         // Normalize the url so we can use Url crate to process ssh urls
         let normalized = normalize_url(url)?;
 
         // Some pre-processing for paths
-        let scheme = if let Ok(scheme) = Scheme::from_str(normalized.scheme()) {
-            scheme
-        } else {
-            return Err(GitUrlParseError::UnsupportedScheme(
-                normalized.scheme().to_string(),
-            ));
-        };
+        let scheme = Scheme::from_str(normalized.scheme())
+            .unwrap_or_else(|_| Scheme::Other(normalized.scheme().to_string()));
         if normalized.path().is_empty() {
             return Err(GitUrlParseError::EmptyPath);
         }
@@ -191,8 +436,6 @@ impl GitUrl {
 
         let git_suffix_check = &urlpath.ends_with(".git");
 
-        // Parse through path for name,owner,organization
-        // Support organizations for Azure Devops
         #[cfg(feature = "tracing")]
         debug!("The urlpath: {:?}", &urlpath);
 
@@ -202,116 +445,112 @@ impl GitUrl {
         // owner = accountname
         // name = reponame
         //
-        // organizations are going to be supported on a per-host basis
-        let mut splitpath = urlpath
-            .rsplit('/')
+        // Everything else is handled by the layout of the host
+        let splitpath = urlpath
+            .split('/')
             .filter(|&s| !s.is_empty())
             .collect::<Vec<&str>>();
 
-        if skip_part_count > 0 {
-            splitpath = splitpath[0..splitpath.len() - skip_part_count].to_vec();
-        }
-
         #[cfg(feature = "tracing")]
-        debug!("rsplit results for metadata: {:?}", splitpath);
+        debug!("split results for metadata: {:?}", splitpath);
+
+        // IPv6 hosts are kept without brackets, with the zone id the `url` crate drops
+        let parsed_host = match normalized.host() {
+            Some(url::Host::Ipv6(addr)) => Some(match host::split_zone(url).1 {
+                Some(zone) => format!("{}%{}", addr, zone),
+                None => addr.to_string(),
+            }),
+            _ => normalized.host_str().map(|h| h.to_string()),
+        };
 
-        let name = splitpath[0].trim_end_matches(".git").to_string();
+        let final_path = match scheme {
+            Scheme::File | Scheme::GitFile => {
+                if let Some(host) = normalized.host_str() {
+                    format!("{}{}", host, urlpath)
+                } else {
+                    urlpath.clone()
+                }
+            }
+            _ => urlpath.clone(),
+        };
 
-        let (owner, subgroups, organization, fullname) = match &scheme {
+        let (provider, repo_path, path_base) = match &scheme {
             // We're not going to assume anything about metadata from a filepath
-            Scheme::File => (None::<String>, None::<String>, None::<String>, name.clone()),
+            Scheme::File | Scheme::GitFile => {
+                let name = match splitpath.last() {
+                    Some(name) => layout::repo_name(name),
+                    None => return Err(GitUrlParseError::UnexpectedFormat),
+                };
+                (
+                    Provider::Generic,
+                    RepoPath {
+                        name,
+                        ..RepoPath::default()
+                    },
+                    home_base(&final_path),
+                )
+            }
             _ => {
-                let mut fullname: Vec<String> = Vec::new();
-
-                if normalized.host_str().is_none() {
-                    return Err(GitUrlParseError::UnsupportedUrlHostFormat);
+                let host = match parsed_host.as_deref() {
+                    Some(host) => host,
+                    None => return Err(GitUrlParseError::UnsupportedUrlHostFormat),
                 };
 
-                match splitpath.len() > 2 {
-                    true => {
-                        #[cfg(feature = "tracing")]
-                        debug!("Found a git provider with an org");
-
-                        // Organization
-                        let organization = splitpath[splitpath.len() - 1].to_string();
-                        fullname.push(organization.clone());
-
-                        // Project/Owner name and subgroups (if any)
-                        let subgroups_and_project_parts =
-                            splitpath[1..splitpath.len() - 1].to_vec();
-                        let project = subgroups_and_project_parts[0].to_string();
-                        let mut subgroups_vec = subgroups_and_project_parts[1..].to_vec();
-                        subgroups_vec.reverse();
-                        let subgroups = if subgroups_vec.is_empty() {
-                            None
-                        } else {
-                            Some(subgroups_vec.join("/"))
-                        };
-                        if let Some(subgroups) = &subgroups {
-                            fullname.push(subgroups.clone());
-                        }
-                        fullname.push(project.clone());
+                // Only urls with an explicit scheme may have a single segment
+                if !url.contains("://") && splitpath.len() < 2 {
+                    return Err(GitUrlParseError::UnexpectedFormat);
+                }
 
-                        // Repo name
-                        fullname.push(name.clone());
+                let provider = registry.classify(host, normalized.port(), &urlpath);
 
-                        (
-                            Some(project),
-                            subgroups,
-                            Some(organization),
-                            fullname.join("/"),
-                        )
+                // A home directory isn't part of the repo path. Bitbucket Server
+                // and SourceHut use `~user` as the owner instead.
+                let path_base = match (&scheme, provider) {
+                    (_, Provider::BitbucketServer | Provider::SourceHut) => PathBase::Default,
+                    (Scheme::Ssh | Scheme::GitSsh | Scheme::Git | Scheme::Sftp, _) => {
+                        home_base(urlpath.trim_start_matches('/'))
                     }
-                    false => {
-                        if !url.starts_with("ssh") && splitpath.len() < 2 {
-                            return Err(GitUrlParseError::UnexpectedFormat);
-                        }
+                    _ => PathBase::Default,
+                };
+                let segments = match path_base {
+                    PathBase::Default => &splitpath[..],
+                    PathBase::Home(_) => &splitpath[1..],
+                };
 
-                        let position = match splitpath.len() {
-                            0 => return Err(GitUrlParseError::UnexpectedFormat),
-                            1 => 0,
-                            _ => 1,
-                        };
-
-                        // push owner
-                        fullname.push(splitpath[position].to_string());
-                        // push name
-                        fullname.push(name.clone());
-
-                        (
-                            Some(splitpath[position].to_string()),
-                            None::<String>,
-                            None::<String>,
-                            fullname.join("/"),
-                        )
-                    }
+                let mut repo_path = match registry.layout(host) {
+                    Some(layout) => layout.split(segments)?,
+                    None => provider.default_layout(host).split(segments)?,
+                };
+                // A repo directly in a home directory has no owner
+                if path_base != PathBase::Default && segments.len() == 1 {
+                    repo_path.owner = None;
                 }
+
+                #[cfg(feature = "tracing")]
+                debug!("Found {:?} repo path: {:?}", provider, repo_path);
+
+                (provider, repo_path, path_base)
             }
         };
 
-        let final_host = match scheme {
-            Scheme::File => None,
-            _ => normalized.host_str().map(|h| h.to_string()),
+        let fullname = match &scheme {
+            Scheme::File | Scheme::GitFile => repo_path.name.clone(),
+            _ => repo_path.fullname(),
         };
 
-        let final_path = match scheme {
-            Scheme::File => {
-                if let Some(host) = normalized.host_str() {
-                    format!("{}{}", host, urlpath)
-                } else {
-                    urlpath
-                }
-            }
-            _ => urlpath,
+        let final_host = match scheme {
+            Scheme::File | Scheme::GitFile => None,
+            _ => parsed_host,
         };
 
         Ok(GitUrl {
             host: final_host,
-            name,
-            owner,
-            subgroups,
-            organization,
+            name: repo_path.name,
+            owner: repo_path.owner,
+            subgroups: repo_path.subgroups,
+            organization: repo_path.organization,
             fullname,
+            helper: None,
             scheme,
             auth_user: match normalized.username().to_string().len() {
                 0 => None,
@@ -320,15 +559,72 @@ impl GitUrl {
             auth_token: normalized.password().map(|p| p.to_string()),
             port: normalized.port(),
             path: final_path,
+            path_base,
             git_suffix: *git_suffix_check,
             scheme_prefix: url.contains("://") || url.starts_with("git:"),
-            _skip_part_count: skip_part_count,
+            provider,
+            extra: repo_path.extra,
+            source: None,
         })
     }
 
     /// Returns a `Result<GitUrl>` after normalizing and parsing `url` for metadata
+    ///
+    /// Errors don't carry `url`. [`GitUrlParseError::with_input`] adds it with
+    /// the span of the offending component and a suggestion, if any.
     pub fn parse(url: &str) -> Result<GitUrl, GitUrlParseError> {
-        GitUrl::parse_with_skips(url, 0)
+        GitUrl::parse_with_registry(url, &HostRegistry::default())
+    }
+
+    /// Returns the url of a local path starting with `~/` after replacing the
+    /// `~` with `home`
+    ///
+    /// Other users' home directories (`~user/`) can't be looked up, so those
+    /// paths and remote urls are returned as they are.
+    pub fn expand_home(&self, home: &str) -> Result<GitUrl, GitUrlParseError> {
+        match (&self.scheme, &self.path_base) {
+            (Scheme::File | Scheme::GitFile, PathBase::Home(None)) => {
+                let rest = &self.path[1..];
+                GitUrl::parse(&format!("{}{}", home.trim_end_matches(['/', '\\']), rest))
+            }
+            _ => Ok(self.clone()),
+        }
+    }
+}
+
+/// Splits `<transport>::<address>` into the name of the remote helper and its
+/// address, like git does
+fn split_helper(url: &str) -> Option<(&str, &str)> {
+    let end = url
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')))
+        .unwrap_or(url.len());
+    match url[end..].strip_prefix("::") {
+        Some(address) if end > 0 => Some((&url[..end], address)),
+        _ => None,
+    }
+}
+
+/// The last component of an address the helper interprets itself, such as the
+/// `repo` of `us-east-1://repo`
+fn helper_repo_name(address: &str) -> String {
+    let last = address
+        .trim_end_matches('/')
+        .rsplit(['/', ':', ' '])
+        .next()
+        .unwrap_or(address)
+        .trim_matches(['\'', '"']);
+    layout::repo_name(last)
+}
+
+/// Returns the home directory `path` starts from, if any
+fn home_base(path: &str) -> PathBase {
+    match path.split('/').next() {
+        Some("~") => PathBase::Home(None),
+        Some(first) => match first.strip_prefix('~') {
+            Some(user) => PathBase::Home(Some(user.to_string())),
+            None => PathBase::Default,
+        },
+        None => PathBase::Default,
     }
 }
 
@@ -339,27 +635,39 @@ impl GitUrl {
 ///
 /// Supports absolute and relative paths
 fn normalize_ssh_url(url: &str) -> Result<Url, GitUrlParseError> {
-    let u = url.split(':').collect::<Vec<&str>>();
-
-    match u.len() {
-        2 => {
+    // The `:`s of an IPv6 host are not separators
+    let colons = host::mask_ipv6(url)
+        .match_indices(':')
+        .map(|(i, _)| i)
+        .collect::<Vec<usize>>();
+
+    match colons[..] {
+        [path] => {
             #[cfg(feature = "tracing")]
-            debug!("Normalizing ssh url: {:?}", u);
-            normalize_url(&format!("ssh://{}/{}", u[0], u[1]))
+            debug!("Normalizing ssh url: {:?}", url);
+            normalize_url(&format!("ssh://{}/{}", &url[..path], &url[path + 1..]))
         }
-        3 => {
+        [port, path] => {
             #[cfg(feature = "tracing")]
-            debug!("Normalizing ssh url with ports: {:?}", u);
-            normalize_url(&format!("ssh://{}:{}/{}", u[0], u[1], u[2]))
+            debug!("Normalizing ssh url with ports: {:?}", url);
+            normalize_url(&format!(
+                "ssh://{}:{}/{}",
+                &url[..port],
+                &url[port + 1..path],
+                &url[path + 1..]
+            ))
         }
-        _default => Err(GitUrlParseError::UnsupportedSshUrlFormat),
+        _ => Err(GitUrlParseError::UnsupportedSshUrlFormat),
     }
 }
 
 /// `normalize_file_path` takes in a filepath and uses `Url::from_file_path()` to parse
 ///
 /// Prepends `file://` to url
-#[cfg(any(unix, windows, target_os = "redox", target_os = "wasi"))]
+#[cfg(all(
+    feature = "std",
+    any(unix, windows, target_os = "redox", target_os = "wasi")
+))]
 fn normalize_file_path(filepath: &str) -> Result<Url, GitUrlParseError> {
     let fp = Url::from_file_path(filepath);
 
@@ -375,9 +683,13 @@ fn normalize_file_path(filepath: &str) -> Result<Url, GitUrlParseError> {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+/// Local paths can't be converted to urls without `std` or a filesystem
+#[cfg(not(all(
+    feature = "std",
+    any(unix, windows, target_os = "redox", target_os = "wasi")
+)))]
 fn normalize_file_path(_filepath: &str) -> Result<Url, GitUrlParseError> {
-    unreachable!()
+    Err(GitUrlParseError::UnsupportedFilePath)
 }
 
 /// `normalize_url` takes in url as `&str` and takes an opinionated approach to identify
@@ -394,8 +706,11 @@ pub fn normalize_url(url: &str) -> Result<Url, GitUrlParseError> {
         return Err(GitUrlParseError::FoundNullBytes);
     }
 
+    // The url crate doesn't support zone ids of IPv6 hosts
+    let (unzoned_url, _zone) = host::split_zone(url);
+
     // We're going to remove any trailing slash before running through Url::parse
-    let trim_url = url.trim_end_matches('/');
+    let trim_url = unzoned_url.trim_end_matches('/');
 
     // TODO: Remove support for this form when I go to next major version.
     // I forget what it supports, and it isn't obvious after searching for examples
@@ -410,9 +725,14 @@ pub fn normalize_url(url: &str) -> Result<Url, GitUrlParseError> {
 
     Ok(match url_parse {
         Ok(u) => {
+            // Unknown schemes are only schemes if followed by `//`
+            let has_authority = url_to_parse
+                .split_once(':')
+                .is_some_and(|(_, rest)| rest.starts_with("//"));
             match Scheme::from_str(u.scheme()) {
-                Ok(_p) => u,
-                Err(_e) => {
+                Ok(Scheme::Other(_)) if has_authority => u,
+                Ok(scheme) if !matches!(scheme, Scheme::Other(_)) => u,
+                _ => {
                     // Catch case when an ssh url is given w/o a user
                     #[cfg(feature = "tracing")]
                     debug!("Scheme parse fail. Assuming a userless ssh url");
@@ -457,6 +777,14 @@ pub fn normalize_url(url: &str) -> Result<Url, GitUrlParseError> {
 // but we don't require it classification or parsing purposes
 // However a path must be specified with a `:`
 fn is_ssh_url(url: &str) -> bool {
+    // The `:`s of an IPv6 host are not separators
+    let url = &host::mask_ipv6(url);
+
+    // A bracketed IPv6 host followed by the path, as in `[::1]:repo.git`
+    if url.starts_with('[') && url.contains("]:") {
+        return true;
+    }
+
     // if we do not have a path
     if !url.contains(':') {
         return false;
@@ -487,7 +815,8 @@ fn is_ssh_url(url: &str) -> bool {
     parts.len() == 2 && parts[0].is_empty() && parts[1].is_empty()
 }
 
-#[derive(Error, Debug, PartialEq, Eq)]
+#[derive(Error, Debug, PartialEq, Eq, IntoStaticStr)]
+#[non_exhaustive]
 pub enum GitUrlParseError {
     #[error("Error from Url crate: {0}")]
     UrlParseError(#[from] url::ParseError),
@@ -512,6 +841,9 @@ pub enum GitUrlParseError {
     #[error("Git Url not in expected format")]
     UnexpectedFormat,
 
+    #[error("Local file paths are not supported on this target")]
+    UnsupportedFilePath,
+
     // FIXME: Keep an eye on this error for removal
     #[error("Git Url for host using unexpected scheme")]
     UnexpectedScheme,
@@ -522,11 +854,48 @@ pub enum GitUrlParseError {
     UnsupportedUrlHostFormat,
     #[error("Git Url not in expected format for SSH")]
     UnsupportedSshUrlFormat,
+    #[error("scp-like ssh urls cannot use port {0}")]
+    UnsupportedScpLikePort(u16),
+    #[error("Paths from a home directory have no https url")]
+    UnsupportedHomePath,
     #[error("Normalized URL has no path")]
     EmptyPath,
 
     #[error("Found null bytes within input url before parsing")]
     FoundNullBytes,
+
+    #[error("Invalid git config on line {0}")]
+    InvalidConfig(usize),
+
+    #[error("Relative url goes above the root of {0}")]
+    RelativeUrlAboveRoot(String),
+
+    #[error("Invalid ssh config on line {0}")]
+    InvalidSshConfig(usize),
+
+    #[error("Failed to read {0}: {1}")]
+    ReadFailed(String, String),
+
+    #[error("Module source uses the {0} getter, not git")]
+    UnsupportedGetter(String),
+
+    #[error("Invalid value for module source parameter {0}")]
+    InvalidSourceParameter(String),
+
+    #[error("No go-import meta tag or known repository for {0}")]
+    MissingGoImport(String),
+
+    #[error("port `{0}` is not numeric")]
+    NonNumericPort(String),
+
+    #[error("Input is longer than {0} bytes")]
+    InputTooLong(usize),
+
+    #[error("Character at byte {0} is not allowed in a uri")]
+    InvalidUriCharacter(usize),
+
+    #[error("{0:?} urls are not allowed")]
+    UnsupportedStyle(UrlStyle),
 }
 
 #[cfg(test)]
@@ -539,18 +908,22 @@ mod tests {
         let expected = GitUrl {
             host: Some("gitlab.example.com".to_string()),
             name: "repo".to_string(),
-            owner: Some("subgroup".to_string()),
+            owner: Some("project".to_string()),
             subgroups: None,
-            organization: None,
-            fullname: "org/subgroup/repo".to_string(),
+            organization: Some("org".to_string()),
+            fullname: "org/project/repo".to_string(),
+            helper: None,
             scheme: Scheme::Ssh,
             auth_user: Some("git".to_string()),
             auth_token: None,
             port: Some(222),
-            path: "/org/subgroup/repo.git".to_string(),
+            path: "org/project/repo.git".to_string(),
+            path_base: PathBase::Default,
             git_suffix: true,
             scheme_prefix: true,
-            _skip_part_count: 0,
+            provider: Provider::GitLab,
+            extra: None,
+            source: None,
         };
 
         assert_eq!(parsed, expected);
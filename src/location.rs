@@ -0,0 +1,493 @@
+use crate::{GitUrl, GitUrlParseError, HostRegistry, Provider};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+use percent_encoding::percent_decode_str;
+use strum::{Display, EnumString, VariantNames};
+use url::Url;
+
+#[cfg(feature = "tracing")]
+use tracing::debug;
+
+/// What a browser url inside a repository points at
+#[derive(Debug, PartialEq, Eq, EnumString, VariantNames, Clone, Display, Copy)]
+#[strum(serialize_all = "kebab_case")]
+pub enum LocationKind {
+    /// A directory listing
+    Tree,
+    /// A rendered file
+    Blob,
+    /// The raw content of a file
+    Raw,
+    /// A file with blame annotations
+    Blame,
+    /// A single commit
+    Commit,
+}
+
+/// An inclusive range of 1-based line numbers within a file
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LineRange {
+    /// The first line
+    pub start: u32,
+    /// The last line, if more than one line is selected
+    pub end: Option<u32>,
+}
+
+impl LineRange {
+    /// Parse the line selection of a url fragment, such as `L10`, `L10-L20`,
+    /// `L10C4-L20C8`, `lines-10:20` or `10-20`
+    pub fn from_fragment(fragment: &str) -> Option<LineRange> {
+        let fragment = fragment
+            .strip_prefix("lines-")
+            .unwrap_or(fragment)
+            .trim_start_matches('L');
+
+        let (start, end) = match fragment.split_once(['-', ':']) {
+            Some((start, end)) => (start, Some(end.trim_start_matches('L'))),
+            None => (fragment, None),
+        };
+
+        let start = line_number(start)?;
+        let end = match end {
+            Some(end) => Some(line_number(end)?),
+            None => None,
+        };
+
+        Some(LineRange {
+            start,
+            end: end.filter(|end| *end != start),
+        })
+    }
+}
+
+/// Strips an optional `C<column>` suffix from a line number
+fn line_number(s: &str) -> Option<u32> {
+    let line = match s.split_once('C') {
+        Some((line, _column)) => line,
+        None => s,
+    };
+    line.parse().ok()
+}
+
+impl fmt::Display for LineRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.end {
+            Some(end) => write!(f, "{}-{}", self.start, end),
+            None => write!(f, "{}", self.start),
+        }
+    }
+}
+
+/// RepoLocation represents a url that points into a repository, such as a link
+/// copied from the browser, split into the repository and the location within it
+///
+/// The clone url of the repository is parsed as a [`GitUrl`] so it can be used
+/// like any other. Urls that do not point inside a repository, such as clone
+/// urls, have no `kind`, `git_ref`, `path` or `lines`.
+///
+/// Refs are assumed not to contain `/` when the url does not say where the ref
+/// ends, which is the case for GitHub, GitLab, Gitea and Bitbucket Cloud.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RepoLocation {
+    /// The repository the url points into
+    pub repo: GitUrl,
+    /// What kind of page the url points at
+    pub kind: Option<LocationKind>,
+    /// The branch, tag or commit
+    pub git_ref: Option<String>,
+    /// The path of a file or directory within the repository, without a leading `/`
+    pub path: Option<String>,
+    /// The selected lines of a file
+    pub lines: Option<LineRange>,
+}
+
+impl FromStr for RepoLocation {
+    type Err = GitUrlParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RepoLocation::parse(s)
+    }
+}
+
+/// The parts of a browser url, relative to the repository
+#[derive(Debug, Default)]
+struct Location {
+    /// Number of leading path segments that name the repository
+    repo_end: usize,
+    /// Path segments of the repository, when they differ from the url
+    repo_segments: Option<Vec<String>>,
+    kind: Option<LocationKind>,
+    git_ref: Option<String>,
+    path: Option<String>,
+    lines: Option<LineRange>,
+}
+
+impl RepoLocation {
+    /// Returns a `Result<RepoLocation>` after splitting `url` into the repository
+    /// and the location within it
+    pub fn parse(url: &str) -> Result<RepoLocation, GitUrlParseError> {
+        RepoLocation::parse_with_registry(url, &HostRegistry::default())
+    }
+
+    /// Returns a `Result<RepoLocation>` like [`RepoLocation::parse`], using
+    /// `registry` to recognize self-hosted instances
+    pub fn parse_with_registry(
+        url: &str,
+        registry: &HostRegistry,
+    ) -> Result<RepoLocation, GitUrlParseError> {
+        let browser_url = match Url::parse(url) {
+            Ok(u) if u.scheme() == "http" || u.scheme() == "https" => u,
+            _ => {
+                return Ok(RepoLocation::from(GitUrl::parse_with_registry(
+                    url, registry,
+                )?))
+            }
+        };
+
+        let host = match browser_url.host_str() {
+            Some(host) => host,
+            None => return Err(GitUrlParseError::UnsupportedUrlHostFormat),
+        };
+        let segments = browser_url
+            .path()
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<&str>>();
+
+        // raw.githubusercontent.com/{owner}/{repo}/{ref}/{path} belongs to a github.com repo
+        let raw_github = host.eq_ignore_ascii_case("raw.githubusercontent.com");
+        let repo_host = match raw_github {
+            true => "github.com",
+            false => host,
+        };
+
+        let mut location = match registry.classify(host, browser_url.port(), browser_url.path()) {
+            _ if raw_github => raw_githubusercontent_location(&segments),
+            Provider::GitLab => gitlab_location(&segments),
+            Provider::BitbucketCloud => bitbucket_cloud_location(&segments),
+            Provider::BitbucketServer => bitbucket_server_location(&browser_url, &segments),
+            Provider::AzureDevOps => azure_devops_location(&browser_url, &segments),
+            Provider::Gitea => gitea_location(&segments),
+            Provider::SourceHut => sourcehut_location(&segments),
+            Provider::GitHub => github_location(&segments),
+            _ => generic_location(&segments),
+        };
+
+        if location.lines.is_none() {
+            location.lines = browser_url.fragment().and_then(LineRange::from_fragment);
+        }
+
+        #[cfg(feature = "tracing")]
+        debug!("Found location in browser url: {:?}", location);
+
+        let repo_path = match &location.repo_segments {
+            Some(repo_segments) => repo_segments.join("/"),
+            None => segments[..location.repo_end.min(segments.len())].join("/"),
+        };
+        let port = match browser_url.port() {
+            Some(port) if !raw_github => format!(":{}", port),
+            _ => String::new(),
+        };
+        let repo_url = format!(
+            "{}://{}{}/{}",
+            browser_url.scheme(),
+            repo_host,
+            port,
+            repo_path
+        );
+
+        Ok(RepoLocation {
+            repo: GitUrl::parse_with_registry(&repo_url, registry)?,
+            kind: location.kind,
+            git_ref: location.git_ref,
+            path: location.path,
+            lines: location.lines,
+        })
+    }
+}
+
+impl From<GitUrl> for RepoLocation {
+    fn from(repo: GitUrl) -> Self {
+        RepoLocation {
+            repo,
+            kind: None,
+            git_ref: None,
+            path: None,
+            lines: None,
+        }
+    }
+}
+
+fn decode(segment: &str) -> String {
+    percent_decode_str(segment).decode_utf8_lossy().to_string()
+}
+
+fn join_path(segments: &[&str]) -> Option<String> {
+    match segments.is_empty() {
+        true => None,
+        false => Some(
+            segments
+                .iter()
+                .map(|s| decode(s))
+                .collect::<Vec<_>>()
+                .join("/"),
+        ),
+    }
+}
+
+/// `{kind}/{ref}/{path...}` after the repository
+fn ref_and_path(repo_end: usize, kind: LocationKind, rest: &[&str]) -> Location {
+    let (git_ref, path) = match rest.split_first() {
+        Some((git_ref, path)) => (Some(decode(git_ref)), join_path(path)),
+        None => (None, None),
+    };
+    Location {
+        repo_end,
+        kind: Some(kind),
+        git_ref,
+        path,
+        ..Location::default()
+    }
+}
+
+fn github_kind(segment: &str) -> Option<LocationKind> {
+    match segment {
+        "tree" => Some(LocationKind::Tree),
+        "blob" => Some(LocationKind::Blob),
+        "raw" => Some(LocationKind::Raw),
+        "blame" => Some(LocationKind::Blame),
+        "commit" => Some(LocationKind::Commit),
+        _ => None,
+    }
+}
+
+/// `{owner}/{repo}/{tree|blob|raw|blame|commit}/{ref}/{path...}`, where other
+/// pages such as `{owner}/{repo}/pull/{number}` belong to the repo as a whole
+fn github_location(segments: &[&str]) -> Location {
+    match segments.get(2).and_then(|s| github_kind(s)) {
+        Some(kind) => ref_and_path(2, kind, &segments[3..]),
+        None => Location {
+            repo_end: segments.len().min(2),
+            ..Location::default()
+        },
+    }
+}
+
+/// The GitHub layout on hosts of unknown providers, which otherwise name the
+/// repo with their whole path
+fn generic_location(segments: &[&str]) -> Location {
+    match segments.get(2).and_then(|s| github_kind(s)) {
+        Some(_) => github_location(segments),
+        None => Location {
+            repo_end: segments.len(),
+            ..Location::default()
+        },
+    }
+}
+
+/// `{owner}/{repo}/{ref}/{path...}` on raw.githubusercontent.com
+fn raw_githubusercontent_location(segments: &[&str]) -> Location {
+    ref_and_path(2, LocationKind::Raw, segments.get(2..).unwrap_or_default())
+}
+
+/// `{group}/{subgroups...}/{repo}/-/{tree|blob|raw|blame|commit}/{ref}/{path...}`
+fn gitlab_location(segments: &[&str]) -> Location {
+    let separator = match segments.iter().position(|s| *s == "-") {
+        Some(pos) => pos,
+        None => {
+            return Location {
+                repo_end: segments.len(),
+                ..Location::default()
+            }
+        }
+    };
+
+    match segments.get(separator + 1).and_then(|s| github_kind(s)) {
+        Some(kind) => ref_and_path(separator, kind, &segments[separator + 2..]),
+        None => Location {
+            repo_end: separator,
+            ..Location::default()
+        },
+    }
+}
+
+/// `{owner}/{repo}/{src|raw|annotate}/{ref}/{path...}` or `{owner}/{repo}/commits/{sha}`
+fn bitbucket_cloud_location(segments: &[&str]) -> Location {
+    let kind = match segments.get(2).copied() {
+        Some("src") => LocationKind::Blob,
+        Some("raw") => LocationKind::Raw,
+        Some("annotate") => LocationKind::Blame,
+        Some("commits") => LocationKind::Commit,
+        _ => {
+            return Location {
+                repo_end: segments.len().min(2),
+                ..Location::default()
+            }
+        }
+    };
+
+    let mut location = ref_and_path(2, kind, &segments[3..]);
+    if kind == LocationKind::Blob && location.path.is_none() {
+        location.kind = Some(LocationKind::Tree);
+    }
+    location
+}
+
+/// `{owner}/{repo}/{src|raw|blame}/{branch|tag|commit}/{ref}/{path...}` or
+/// `{owner}/{repo}/commit/{sha}`
+fn gitea_location(segments: &[&str]) -> Location {
+    let kind = match segments.get(2).copied() {
+        Some("src") => LocationKind::Blob,
+        Some("raw") => LocationKind::Raw,
+        Some("blame") => LocationKind::Blame,
+        Some("commit") => return ref_and_path(2, LocationKind::Commit, &segments[3..]),
+        _ => {
+            return Location {
+                repo_end: segments.len().min(2),
+                ..Location::default()
+            }
+        }
+    };
+
+    let rest = match segments.get(3).copied() {
+        Some("branch" | "tag" | "commit") => &segments[4..],
+        _ => &segments[3..],
+    };
+    let mut location = ref_and_path(2, kind, rest);
+    if kind == LocationKind::Blob && location.path.is_none() {
+        location.kind = Some(LocationKind::Tree);
+    }
+    location
+}
+
+/// `~{owner}/{repo}/{tree|blob}/{ref}/item/{path...}`, `~{owner}/{repo}/log/...`
+/// or `~{owner}/{repo}/commit/{sha}`
+fn sourcehut_location(segments: &[&str]) -> Location {
+    let kind = match segments.get(2).copied() {
+        Some("tree") => LocationKind::Tree,
+        Some("blob") => LocationKind::Blob,
+        Some("commit") => LocationKind::Commit,
+        _ => {
+            return Location {
+                repo_end: segments.len().min(2),
+                ..Location::default()
+            }
+        }
+    };
+
+    let rest = &segments[3..];
+    match rest {
+        [git_ref, "item", path @ ..] => Location {
+            repo_end: 2,
+            kind: Some(kind),
+            git_ref: Some(decode(git_ref)),
+            path: join_path(path),
+            ..Location::default()
+        },
+        _ => ref_and_path(2, kind, rest),
+    }
+}
+
+/// `projects/{key}/repos/{repo}/{browse|raw}/{path...}?at={ref}` or
+/// `projects/{key}/repos/{repo}/commits/{sha}`, cloned from `scm/{key}/{repo}`
+fn bitbucket_server_location(url: &Url, segments: &[&str]) -> Location {
+    let (key, repo, rest) = match segments {
+        [kind @ ("projects" | "users"), key, "repos", repo, rest @ ..] => {
+            let key = match *kind {
+                "users" => format!("~{}", key),
+                _ => key.to_string(),
+            };
+            (key, repo.to_string(), rest)
+        }
+        _ => {
+            return Location {
+                repo_end: segments.len(),
+                ..Location::default()
+            }
+        }
+    };
+
+    let git_ref = url.query_pairs().find(|(k, _)| k == "at").map(|(_, v)| {
+        let v = v.trim_start_matches("refs/heads/");
+        v.trim_start_matches("refs/tags/").to_string()
+    });
+
+    let (kind, git_ref, path) = match rest {
+        ["browse", path @ ..] => (
+            Some(match path.is_empty() {
+                true => LocationKind::Tree,
+                false => LocationKind::Blob,
+            }),
+            git_ref,
+            join_path(path),
+        ),
+        ["raw", path @ ..] => (Some(LocationKind::Raw), git_ref, join_path(path)),
+        ["commits", sha, ..] => (Some(LocationKind::Commit), Some(decode(sha)), None),
+        _ => (None, git_ref, None),
+    };
+
+    Location {
+        repo_segments: Some(vec!["scm".to_string(), key, repo]),
+        kind,
+        git_ref,
+        path,
+        ..Location::default()
+    }
+}
+
+/// `.../_git/{repo}?path=/{path}&version=GB{branch}&line=10&lineEnd=20` or
+/// `.../_git/{repo}/commit/{sha}`
+fn azure_devops_location(url: &Url, segments: &[&str]) -> Location {
+    let repo_end = match segments.iter().position(|s| s.eq_ignore_ascii_case("_git")) {
+        Some(pos) => (pos + 2).min(segments.len()),
+        None => {
+            return Location {
+                repo_end: segments.len(),
+                ..Location::default()
+            }
+        }
+    };
+
+    if let ["commit", sha, ..] = &segments[repo_end..] {
+        return Location {
+            repo_end,
+            kind: Some(LocationKind::Commit),
+            git_ref: Some(decode(sha)),
+            ..Location::default()
+        };
+    }
+
+    let mut location = Location {
+        repo_end,
+        ..Location::default()
+    };
+    let mut line = None;
+    let mut line_end = None;
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "path" => {
+                let path = value.trim_start_matches('/');
+                if !path.is_empty() {
+                    location.path = Some(path.to_string());
+                }
+            }
+            // GB = branch, GT = tag, GC = commit
+            "version" => location.git_ref = value.get(2..).map(|v| v.to_string()),
+            "line" => line = value.parse().ok(),
+            "lineEnd" => line_end = value.parse().ok(),
+            _ => {}
+        }
+    }
+    if location.path.is_some() {
+        location.kind = Some(LocationKind::Blob);
+    }
+    location.lines = line.map(|start| LineRange {
+        start,
+        end: line_end.filter(|end| *end != start),
+    });
+    location
+}
@@ -0,0 +1,175 @@
+use crate::{GitUrl, GitUrlParseError, PathBase, Provider, Scheme};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+/// NpmSpec is a git dependency as written in a `package.json`, such as
+/// `github:user/repo#semver:^1.0` or `git+ssh://git@host:repo.git#commit`
+///
+/// The shortcuts `github:`, `gitlab:`, `bitbucket:` and `gist:`, and bare
+/// `user/repo` for GitHub, are expanded to https urls. Other specs, such as the
+/// scp-like `git@host:user/repo.git`, are parsed with [`GitUrl::parse`], but
+/// local paths are not git dependencies. The fragment after `#`
+/// holds a committish, or `::`-separated `semver:<range>` and `path:<dir>`
+/// entries.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NpmSpec {
+    /// The repository of the dependency
+    pub repo: GitUrl,
+    /// The branch, tag or commit to check out
+    pub committish: Option<String>,
+    /// The semver range matched against the tags of the repository
+    pub semver: Option<String>,
+    /// The directory of the package within the repository
+    pub path: Option<String>,
+}
+
+impl FromStr for NpmSpec {
+    type Err = GitUrlParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NpmSpec::parse(s)
+    }
+}
+
+impl NpmSpec {
+    /// Returns a `Result<NpmSpec>` after expanding shortcuts and splitting off
+    /// the fragment of `spec`
+    pub fn parse(spec: &str) -> Result<NpmSpec, GitUrlParseError> {
+        let (url, fragment) = match spec.split_once('#') {
+            Some((url, fragment)) => (url, Some(fragment)),
+            None => (spec, None),
+        };
+
+        let repo = match url.split_once(':') {
+            Some(("github", path)) => shortcut("github.com", path)?,
+            Some(("gitlab", path)) => shortcut("gitlab.com", path)?,
+            Some(("bitbucket", path)) => shortcut("bitbucket.org", path)?,
+            Some(("gist", path)) => gist(path)?,
+            _ if is_github_shorthand(url) => shortcut("github.com", url)?,
+            _ => GitUrl::parse(&scp_like_after_scheme(url))?,
+        };
+        // npm installs local paths as directories rather than git repositories
+        if repo.scheme == Scheme::File && !repo.scheme_prefix {
+            return Err(GitUrlParseError::UnexpectedFormat);
+        }
+
+        let mut npm_spec = NpmSpec {
+            repo,
+            committish: None,
+            semver: None,
+            path: None,
+        };
+        for entry in fragment.into_iter().flat_map(|f| f.split("::")) {
+            if let Some(range) = entry.strip_prefix("semver:") {
+                npm_spec.semver = Some(range.to_string());
+            } else if let Some(path) = entry.strip_prefix("path:") {
+                npm_spec.path = Some(path.trim_start_matches('/').to_string());
+            } else if !entry.is_empty() {
+                npm_spec.committish = Some(entry.to_string());
+            }
+        }
+        Ok(npm_spec)
+    }
+}
+
+/// Formats the repository url followed by the fragment, if any
+impl fmt::Display for NpmSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.repo)?;
+
+        let entries = [
+            self.committish.clone(),
+            self.semver
+                .as_ref()
+                .map(|range| format!("semver:{}", range)),
+            self.path.as_ref().map(|path| format!("path:{}", path)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<String>>();
+        if !entries.is_empty() {
+            write!(f, "#{}", entries.join("::"))?;
+        }
+        Ok(())
+    }
+}
+
+/// `user/repo` without a scheme, which npm resolves on GitHub
+fn is_github_shorthand(url: &str) -> bool {
+    match url.split_once('/') {
+        Some((user, repo)) => {
+            !user.is_empty()
+                && !repo.is_empty()
+                && !url.starts_with(['.', '-', '@'])
+                && !url.contains([':', '@', '%', '\\'])
+                && !repo.contains('/')
+                && !url.chars().any(|c| c.is_whitespace())
+        }
+        None => false,
+    }
+}
+
+/// Expands `github:user/repo` and the like to an https url
+fn shortcut(host: &str, path: &str) -> Result<GitUrl, GitUrlParseError> {
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        return Err(GitUrlParseError::EmptyPath);
+    }
+    GitUrl::parse(&format!(
+        "https://{}/{}.git",
+        host,
+        path.trim_end_matches(".git")
+    ))
+}
+
+/// Expands `gist:id` or `gist:user/id`, which name a gist by its id alone
+fn gist(path: &str) -> Result<GitUrl, GitUrlParseError> {
+    let id = match path.trim_matches('/').rsplit_once('/') {
+        Some((_user, id)) => id,
+        None => path.trim_matches('/'),
+    };
+    if id.is_empty() {
+        return Err(GitUrlParseError::EmptyPath);
+    }
+    let host = "gist.github.com";
+    let path = format!("/{}.git", id);
+    Ok(GitUrl {
+        host: Some(host.to_string()),
+        name: id.to_string(),
+        fullname: id.to_string(),
+        scheme: Scheme::Https,
+        provider: Provider::detect(host, None, &path),
+        path,
+        path_base: PathBase::Default,
+        git_suffix: true,
+        scheme_prefix: true,
+        ..GitUrl::default()
+    })
+}
+
+/// npm accepts the scp-like `:` after the host of an url with a scheme, as in
+/// `git+ssh://git@host:repo.git`, which is rewritten to a `/`
+fn scp_like_after_scheme(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    let authority_end = rest.find('/').unwrap_or(rest.len());
+    let authority = &rest[..authority_end];
+    let host_start = authority.rfind('@').map_or(0, |at| at + 1);
+    match authority[host_start..].rfind(':') {
+        // Ports are numeric, and IPv6 hosts are in brackets
+        Some(colon)
+            if !authority.ends_with(']')
+                && !authority[host_start + colon + 1..]
+                    .chars()
+                    .all(|c| c.is_ascii_digit()) =>
+        {
+            let colon = host_start + colon;
+            format!("{}://{}/{}", scheme, &rest[..colon], &rest[colon + 1..])
+        }
+        _ => url.to_string(),
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{GitUrl, GitUrlParseError, Scheme, UrlStyle};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// ParseOptions are the rules [`GitUrl::parse_with`] accepts inputs by
+///
+/// The default options accept everything [`GitUrl::parse`] does.
+///
+/// ```
+/// use git_url_parse::{GitUrl, ParseOptions, Scheme};
+///
+/// let options = ParseOptions::new()
+///     .local_paths(false)
+///     .default_host("github.com")
+///     .allowed_schemes(&[Scheme::Https, Scheme::Ssh])
+///     .max_length(2048);
+///
+/// let url = GitUrl::parse_with("owner/repo", &options).expect("URL parse failed");
+/// assert_eq!(url.to_string(), "https://github.com/owner/repo");
+/// assert!(GitUrl::parse_with("../repo.git", &options).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParseOptions {
+    strict: bool,
+    git_shorthand: bool,
+    local_paths: bool,
+    default_host: Option<String>,
+    allowed_schemes: Option<Vec<Scheme>>,
+    max_length: Option<usize>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            strict: false,
+            git_shorthand: true,
+            local_paths: true,
+            default_host: None,
+            allowed_schemes: None,
+            max_length: None,
+        }
+    }
+}
+
+impl GitUrl {
+    /// Returns a `Result<GitUrl>` like [`GitUrl::parse`], if `url` is accepted
+    /// by `options`
+    ///
+    /// Errors are found in `url`, also when it is expanded with the default
+    /// host, so [`GitUrlParseError::with_input`] gives spans within it.
+    pub fn parse_with(url: &str, options: &ParseOptions) -> Result<GitUrl, GitUrlParseError> {
+        options.parse(url)
+    }
+}
+
+impl ParseOptions {
+    /// Returns the options of [`GitUrl::parse`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only accept urls with a scheme and `//`, written with the characters
+    /// RFC 3986 allows. Defaults to `false`, which accepts what git does
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Accept the deprecated `git:host/path` form of `git://` urls. Defaults
+    /// to `true`
+    pub fn git_shorthand(mut self, git_shorthand: bool) -> Self {
+        self.git_shorthand = git_shorthand;
+        self
+    }
+
+    /// Accept local paths without a `file://` scheme, such as `../repo.git`.
+    /// Defaults to `true`
+    pub fn local_paths(mut self, local_paths: bool) -> Self {
+        self.local_paths = local_paths;
+        self
+    }
+
+    /// Parse a bare `owner/repo` as `https://{host}/owner/repo` instead of a
+    /// local path
+    pub fn default_host(mut self, host: &str) -> Self {
+        self.default_host = Some(host.to_string());
+        self
+    }
+
+    /// Only accept urls with one of `schemes`. scp-like urls have
+    /// [`Scheme::Ssh`] and local paths have [`Scheme::File`]
+    pub fn allowed_schemes(mut self, schemes: &[Scheme]) -> Self {
+        self.allowed_schemes = Some(schemes.to_vec());
+        self
+    }
+
+    /// Reject inputs longer than `max_length` bytes before parsing them
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    fn parse(&self, url: &str) -> Result<GitUrl, GitUrlParseError> {
+        if let Some(max_length) = self.max_length {
+            if url.len() > max_length {
+                return Err(GitUrlParseError::InputTooLong(max_length));
+            }
+        }
+        if self.strict {
+            if let Some(pos) = invalid_uri_character(url) {
+                return Err(GitUrlParseError::InvalidUriCharacter(pos));
+            }
+        }
+
+        let parsed = match &self.default_host {
+            Some(host) if is_owner_repo(url) => {
+                GitUrl::parse(&format!("https://{}/{}", host, url))?
+            }
+            _ => GitUrl::parse(url)?,
+        };
+
+        let style = parsed
+            .source
+            .as_ref()
+            .map_or(UrlStyle::default(), |source| source.style);
+        let style_allowed = match style {
+            UrlStyle::Uri => true,
+            _ if self.strict => false,
+            UrlStyle::ShortGit => self.git_shorthand,
+            UrlStyle::LocalPath | UrlStyle::WindowsPath => self.local_paths,
+            UrlStyle::ScpLike | UrlStyle::Opaque => true,
+        };
+        if !style_allowed {
+            return Err(GitUrlParseError::UnsupportedStyle(style));
+        }
+
+        match &self.allowed_schemes {
+            Some(schemes) if !schemes.contains(&parsed.scheme) => Err(
+                GitUrlParseError::UnsupportedScheme(parsed.scheme.to_string()),
+            ),
+            _ => Ok(parsed),
+        }
+    }
+}
+
+/// A bare `owner/repo`, which git would take for a local path
+fn is_owner_repo(url: &str) -> bool {
+    let segments: Vec<&str> = url.trim_end_matches('/').split('/').collect();
+    match segments[..] {
+        [owner, repo] => [owner, repo].iter().all(|segment| {
+            !segment.is_empty()
+                && !segment.starts_with(['.', '~'])
+                && !segment.contains([':', '@', '\\'])
+        }),
+        _ => false,
+    }
+}
+
+/// The byte offset of the first character RFC 3986 doesn't allow in a uri,
+/// including a `%` that doesn't start a percent-encoded octet
+fn invalid_uri_character(url: &str) -> Option<usize> {
+    let bytes = url.as_bytes();
+    bytes.iter().enumerate().find_map(|(i, b)| {
+        let allowed = match b {
+            b'%' => bytes
+                .get(i + 1..i + 3)
+                .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit)),
+            b if b.is_ascii_alphanumeric() => true,
+            _ => b"-._~:/?#[]@!$&'()*+,;=".contains(b),
+        };
+        (!allowed).then_some(i)
+    })
+}
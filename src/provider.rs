@@ -0,0 +1,186 @@
+use crate::layout::{AzureDevOpsLayout, DefaultLayout, HostLayout, PrefixLayout};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::sync::Arc;
+use strum::{Display, EnumString, VariantNames};
+
+/// Git hosting services that can be recognized from a parsed url
+#[derive(Debug, PartialEq, Eq, Hash, EnumString, VariantNames, Clone, Display, Copy, Default)]
+#[strum(ascii_case_insensitive)]
+pub enum Provider {
+    /// github.com and GitHub Enterprise Server
+    #[strum(serialize = "github")]
+    GitHub,
+    /// gitlab.com and self-managed GitLab
+    #[strum(serialize = "gitlab")]
+    GitLab,
+    /// bitbucket.org
+    #[strum(serialize = "bitbucket-cloud")]
+    BitbucketCloud,
+    /// Self-hosted Bitbucket Server / Data Center
+    #[strum(serialize = "bitbucket-server")]
+    BitbucketServer,
+    /// dev.azure.com, `{org}.visualstudio.com` and on-prem TFS
+    #[strum(serialize = "azure-devops")]
+    AzureDevOps,
+    /// Gitea and its forks, such as Forgejo and Codeberg
+    #[strum(serialize = "gitea")]
+    Gitea,
+    /// sr.ht
+    #[strum(serialize = "sourcehut")]
+    SourceHut,
+    /// Gerrit Code Review
+    #[strum(serialize = "gerrit")]
+    Gerrit,
+    /// Any host we have no specific knowledge of
+    #[default]
+    #[strum(serialize = "generic")]
+    Generic,
+}
+
+impl Provider {
+    /// Classify a host using its name, port and the shape of the repo path
+    ///
+    /// `path` is the path of the url, with or without its leading `/`.
+    /// Well-known public hosts are matched first, then path conventions
+    /// (such as Azure DevOps `_git` or Bitbucket Server `scm/`), then
+    /// common naming of self-hosted instances (such as `gitlab.example.com`).
+    pub fn detect(host: &str, port: Option<u16>, path: &str) -> Provider {
+        // Hosts are usually lowercase already
+        let host = match host.bytes().any(|b| b.is_ascii_uppercase()) {
+            true => Cow::Owned(host.to_ascii_lowercase()),
+            false => Cow::Borrowed(host),
+        };
+
+        match host.as_ref() {
+            "github.com" | "www.github.com" | "ssh.github.com" | "gist.github.com" => {
+                return Provider::GitHub
+            }
+            "gitlab.com" | "www.gitlab.com" | "altssh.gitlab.com" => return Provider::GitLab,
+            "bitbucket.org" | "www.bitbucket.org" | "altssh.bitbucket.org" => {
+                return Provider::BitbucketCloud
+            }
+            "dev.azure.com" | "ssh.dev.azure.com" | "vs-ssh.visualstudio.com" => {
+                return Provider::AzureDevOps
+            }
+            "codeberg.org" | "gitea.com" => return Provider::Gitea,
+            _ => {}
+        }
+
+        if host.ends_with(".visualstudio.com") {
+            return Provider::AzureDevOps;
+        }
+        if host == "sr.ht" || host.ends_with(".sr.ht") {
+            return Provider::SourceHut;
+        }
+        if host.ends_with(".googlesource.com") {
+            return Provider::Gerrit;
+        }
+
+        let mut segments = path.split('/').filter(|s| !s.is_empty());
+        let first_segment = segments.next();
+        if first_segment == Some("_git") || segments.any(|s| s == "_git") {
+            return Provider::AzureDevOps;
+        }
+        if first_segment == Some("scm") || port == Some(7999) {
+            return Provider::BitbucketServer;
+        }
+        if port == Some(29418) {
+            return Provider::Gerrit;
+        }
+
+        let first_label = host.split('.').next().unwrap_or_default();
+        match first_label {
+            "github" => Provider::GitHub,
+            "gitlab" => Provider::GitLab,
+            "bitbucket" | "stash" => Provider::BitbucketServer,
+            "gitea" | "forgejo" | "codeberg" => Provider::Gitea,
+            "gerrit" => Provider::Gerrit,
+            "tfs" | "azure-devops" | "azuredevops" => Provider::AzureDevOps,
+            _ => Provider::Generic,
+        }
+    }
+
+    /// Returns the port the provider serves git over ssh on, when it isn't 22
+    pub fn ssh_port(&self) -> Option<u16> {
+        match self {
+            Provider::BitbucketServer => Some(7999),
+            Provider::Gerrit => Some(29418),
+            _ => None,
+        }
+    }
+
+    /// Returns the layout of repo paths used by this provider on `host` when no
+    /// layout was registered for the host
+    pub fn default_layout(&self, host: &str) -> Box<dyn HostLayout> {
+        match self {
+            Provider::BitbucketServer => Box::new(PrefixLayout::new("scm")),
+            Provider::AzureDevOps => {
+                let host = host.to_ascii_lowercase();
+                match host.strip_suffix(".visualstudio.com") {
+                    Some(organization)
+                        if !organization.contains('.') && organization != "vs-ssh" =>
+                    {
+                        Box::new(AzureDevOpsLayout::with_organization(organization))
+                    }
+                    _ => Box::new(AzureDevOpsLayout::default()),
+                }
+            }
+            _ => Box::new(DefaultLayout),
+        }
+    }
+}
+
+/// Caller-supplied knowledge about self-hosted git services
+///
+/// Hosts registered here take precedence over [`Provider::detect`] and
+/// [`Provider::default_layout`].
+/// Host names are matched case-insensitively and without the port.
+#[derive(Debug, Default, Clone)]
+pub struct HostRegistry {
+    providers: BTreeMap<String, Provider>,
+    layouts: BTreeMap<String, Arc<dyn HostLayout>>,
+}
+
+impl HostRegistry {
+    /// Returns an empty registry, which makes parsing fall back to [`Provider::detect`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `host` as an instance of `provider`
+    pub fn register(&mut self, host: &str, provider: Provider) -> &mut Self {
+        self.providers.insert(host.to_ascii_lowercase(), provider);
+        self
+    }
+
+    /// Register the path `layout` used by `host`
+    pub fn register_layout<L>(&mut self, host: &str, layout: L) -> &mut Self
+    where
+        L: HostLayout + 'static,
+    {
+        self.layouts
+            .insert(host.to_ascii_lowercase(), Arc::new(layout));
+        self
+    }
+
+    /// Returns the layout registered for `host`, if any
+    pub fn layout(&self, host: &str) -> Option<&dyn HostLayout> {
+        self.layouts
+            .get(&host.to_ascii_lowercase())
+            .map(|layout| layout.as_ref())
+    }
+
+    /// Returns the provider registered for `host`, if any
+    pub fn provider(&self, host: &str) -> Option<Provider> {
+        self.providers.get(&host.to_ascii_lowercase()).copied()
+    }
+
+    /// Returns the registered provider for `host`, or detects one from the url parts
+    pub fn classify(&self, host: &str, port: Option<u16>, path: &str) -> Provider {
+        self.provider(host)
+            .unwrap_or_else(|| Provider::detect(host, port, path))
+    }
+}
@@ -0,0 +1,190 @@
+use crate::config::parse_config;
+use crate::{GitUrl, GitUrlParseError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A `url.<base>.insteadOf` or `url.<base>.pushInsteadOf` rule
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RewriteRule {
+    /// The prefix urls are rewritten to
+    pub base: String,
+    /// The prefix that is replaced by `base`
+    pub instead_of: String,
+    /// Whether this is a `pushInsteadOf` rule, which only applies to push urls
+    pub push_only: bool,
+}
+
+impl RewriteRule {
+    /// Returns the rule that rewrites `from` into `to`, using the shortest
+    /// prefixes that still end at a `/` or `:`
+    ///
+    /// Applied to other urls of the same host, the rule rewrites them the same way.
+    ///
+    /// ```
+    /// use git_url_parse::RewriteRule;
+    ///
+    /// let rule = RewriteRule::between(
+    ///     "git@github.com:owner/repo.git",
+    ///     "https://x-access-token@github.com/owner/repo.git",
+    /// );
+    /// assert_eq!(rule.base, "https://x-access-token@github.com/");
+    /// assert_eq!(rule.instead_of, "git@github.com:");
+    /// ```
+    pub fn between(from: &str, to: &str) -> Self {
+        let common = from
+            .chars()
+            .rev()
+            .zip(to.chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum::<usize>();
+
+        // Shrink the common suffix until both prefixes end at a separator
+        let mut suffix = &from[from.len() - common..];
+        loop {
+            let from_prefix = &from[..from.len() - suffix.len()];
+            let to_prefix = &to[..to.len() - suffix.len()];
+            if suffix.is_empty()
+                || (from_prefix.ends_with(['/', ':']) && to_prefix.ends_with(['/', ':']))
+            {
+                return RewriteRule {
+                    base: to_prefix.to_string(),
+                    instead_of: from_prefix.to_string(),
+                    push_only: false,
+                };
+            }
+            let mut chars = suffix.chars();
+            chars.next();
+            suffix = chars.as_str();
+        }
+    }
+}
+
+/// Formats the rule in gitconfig syntax, ready to be appended to a config file
+impl fmt::Display for RewriteRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let key = match self.push_only {
+            true => "pushInsteadOf",
+            false => "insteadOf",
+        };
+        writeln!(f, "[url \"{}\"]", escape(&self.base))?;
+        writeln!(f, "\t{} = \"{}\"", key, escape(&self.instead_of))
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// The urls git contacts for a remote after rewriting
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RemoteUrls {
+    /// The url used by `git fetch`
+    pub fetch: GitUrl,
+    /// The url used by `git push`
+    pub push: GitUrl,
+}
+
+/// UrlRewriter applies `insteadOf` and `pushInsteadOf` rules the way git does
+/// before it contacts a remote
+///
+/// Of all rules whose `instead_of` is a prefix of the url, the longest one wins.
+/// Push urls are rewritten with `pushInsteadOf` rules if any matches, and with
+/// `insteadOf` rules otherwise.
+#[derive(Debug, Default, Clone)]
+pub struct UrlRewriter {
+    rules: Vec<RewriteRule>,
+}
+
+impl UrlRewriter {
+    /// Returns a rewriter without rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a rewriter with the rules of gitconfig-format text, such as the
+    /// contents of `~/.gitconfig` or `.git/config`
+    pub fn from_config(text: &str) -> Result<Self, GitUrlParseError> {
+        let mut rewriter = Self::new();
+        for entry in parse_config(text)? {
+            let push_only = match (entry.section.as_str(), entry.key.as_str()) {
+                ("url", "insteadof") => false,
+                ("url", "pushinsteadof") => true,
+                _ => continue,
+            };
+            let (Some(base), Some(instead_of)) = (entry.subsection, entry.value) else {
+                return Err(GitUrlParseError::InvalidConfig(entry.line));
+            };
+            rewriter.rules.push(RewriteRule {
+                base,
+                instead_of,
+                push_only,
+            });
+        }
+        Ok(rewriter)
+    }
+
+    /// Adds an `insteadOf` rule that rewrites urls starting with `instead_of`
+    /// to start with `base`
+    pub fn instead_of(&mut self, base: &str, instead_of: &str) -> &mut Self {
+        self.rules.push(RewriteRule {
+            base: base.to_string(),
+            instead_of: instead_of.to_string(),
+            push_only: false,
+        });
+        self
+    }
+
+    /// Adds a `pushInsteadOf` rule, which is like [`UrlRewriter::instead_of`]
+    /// but only applies to push urls
+    pub fn push_instead_of(&mut self, base: &str, instead_of: &str) -> &mut Self {
+        self.rules.push(RewriteRule {
+            base: base.to_string(),
+            instead_of: instead_of.to_string(),
+            push_only: true,
+        });
+        self
+    }
+
+    /// Returns the rules in the order they were added
+    pub fn rules(&self) -> &[RewriteRule] {
+        &self.rules
+    }
+
+    /// Returns `url` as git fetches it
+    pub fn rewrite_fetch(&self, url: &str) -> String {
+        self.rewrite(url, false).unwrap_or_else(|| url.to_string())
+    }
+
+    /// Returns `url` as git pushes to it
+    ///
+    /// This applies to urls from `remote.<name>.url`. Urls from
+    /// `remote.<name>.pushurl` are rewritten by [`UrlRewriter::rewrite_fetch`].
+    pub fn rewrite_push(&self, url: &str) -> String {
+        self.rewrite(url, true)
+            .unwrap_or_else(|| self.rewrite_fetch(url))
+    }
+
+    /// Rewrites and parses the fetch and push urls of a remote with `url`
+    pub fn resolve(&self, url: &str) -> Result<RemoteUrls, GitUrlParseError> {
+        Ok(RemoteUrls {
+            fetch: GitUrl::parse(&self.rewrite_fetch(url))?,
+            push: GitUrl::parse(&self.rewrite_push(url))?,
+        })
+    }
+
+    /// Applies the longest matching rule, preferring the first of equally long ones
+    fn rewrite(&self, url: &str, push: bool) -> Option<String> {
+        let mut longest: Option<&RewriteRule> = None;
+        for rule in self.rules.iter().filter(|r| r.push_only == push) {
+            if url.starts_with(&rule.instead_of)
+                && longest.is_none_or(|l| rule.instead_of.len() > l.instead_of.len())
+            {
+                longest = Some(rule);
+            }
+        }
+        longest.map(|rule| format!("{}{}", rule.base, &url[rule.instead_of.len()..]))
+    }
+}
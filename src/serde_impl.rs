@@ -0,0 +1,81 @@
+use crate::{ErrorContext, GitUrlParseError, PathBase, Provider, Scheme};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::str::FromStr;
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use strum::VariantNames;
+
+/// `Scheme` and `Provider` are (de)serialized with the same names they are
+/// displayed and parsed with, e.g. `"git+ssh"` and `"azure-devops"`
+macro_rules! serde_via_str {
+    ($t:ty) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                <$t>::from_str(&s).map_err(|_| de::Error::unknown_variant(&s, <$t>::VARIANTS))
+            }
+        }
+    };
+}
+
+serde_via_str!(Scheme);
+serde_via_str!(Provider);
+
+/// `PathBase` is serialized as the `"~"` or `"~user"` that starts the path, or
+/// as `null` if the path is used as written
+impl Serialize for PathBase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            PathBase::Default => serializer.serialize_none(),
+            home => serializer.collect_str(home),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PathBase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            None => Ok(PathBase::Default),
+            Some(home) => match home.strip_prefix('~') {
+                Some("") => Ok(PathBase::Home(None)),
+                Some(user) => Ok(PathBase::Home(Some(user.to_string()))),
+                None => Err(de::Error::custom(format!("invalid path base: {}", home))),
+            },
+        }
+    }
+}
+
+/// Errors are serialized as their variant name and message, e.g.
+/// `{"kind": "UnexpectedFormat", "message": "Git Url not in expected format"}`
+impl Serialize for GitUrlParseError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let kind: &'static str = self.into();
+        let mut state = serializer.serialize_struct("GitUrlParseError", 2)?;
+        state.serialize_field("kind", kind)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+/// Errors with context are serialized like [`GitUrlParseError`], with the
+/// `input`, `span` and `suggestion` added
+impl Serialize for ErrorContext {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let kind: &'static str = (&self.error).into();
+        let mut state = serializer.serialize_struct("ErrorContext", 5)?;
+        state.serialize_field("kind", kind)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("input", &self.input)?;
+        state.serialize_field("span", &[self.span.start, self.span.end])?;
+        state.serialize_field("suggestion", &self.suggestion)?;
+        state.end()
+    }
+}
@@ -0,0 +1,64 @@
+//! Serialize a [`GitUrl`] as its url string instead of a map of its fields
+//!
+//! ```
+//! use git_url_parse::GitUrl;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Remote {
+//!     name: String,
+//!     #[serde(with = "git_url_parse::serde_str")]
+//!     url: GitUrl,
+//!     #[serde(default, with = "git_url_parse::serde_str::option")]
+//!     push_url: Option<GitUrl>,
+//! }
+//! ```
+//!
+//! Urls are written with `Display` and read back with `FromStr`.
+
+use crate::GitUrl;
+use alloc::string::String;
+use core::str::FromStr;
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serializer};
+
+/// Serialize `url` as a string
+pub fn serialize<S: Serializer>(url: &GitUrl, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(url)
+}
+
+/// Deserialize a `GitUrl` by parsing a string
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<GitUrl, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    GitUrl::from_str(&s).map_err(de::Error::custom)
+}
+
+/// The same as the parent module, for `Option<GitUrl>`
+pub mod option {
+    use crate::GitUrl;
+    use alloc::string::String;
+    use core::str::FromStr;
+    use serde::de::{self, Deserializer};
+    use serde::{Deserialize, Serializer};
+
+    /// Serialize `url` as a string, or as none
+    pub fn serialize<S: Serializer>(
+        url: &Option<GitUrl>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match url {
+            Some(url) => serializer.collect_str(url),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an optional `GitUrl` by parsing a string
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<GitUrl>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) => GitUrl::from_str(&s).map(Some).map_err(de::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use crate::identity::Fnv1aHasher;
+use crate::{host, is_ssh_url, GitUrl, Scheme};
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Range;
+
+/// The syntax a url was written in
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum UrlStyle {
+    /// A url with a scheme, such as `https://host/org/repo.git`
+    #[default]
+    Uri,
+    /// An scp-like ssh url, such as `git@host:org/repo.git`
+    ScpLike,
+    /// The short form of a `git://` url, `git:host/org/repo.git`
+    ShortGit,
+    /// A local path, such as `../repo.git` or `~/repo.git`
+    LocalPath,
+    /// A local path with `\` separators, such as `..\repo.git`
+    WindowsPath,
+    /// An address only meaningful to a remote helper, such as
+    /// `codecommit::us-east-1://repo`
+    Opaque,
+}
+
+/// UrlSource is the input a [`GitUrl`] was parsed from, with its syntax style
+/// and the byte ranges of its components
+///
+/// [`GitUrl`]'s `Display` writes the input as it was while the parts of the url
+/// it writes are unchanged.
+#[derive(PartialEq, Eq, Clone)]
+pub struct UrlSource {
+    /// The input given to [`GitUrl::parse`]
+    pub input: String,
+    /// The syntax of the input, after the remote helper
+    pub style: UrlStyle,
+    /// The byte range of the scheme, before `://`
+    pub scheme: Option<Range<usize>>,
+    /// The byte range of the user, before `:` or `@`
+    pub user: Option<Range<usize>>,
+    /// The byte range of the token, between `:` and `@`
+    pub token: Option<Range<usize>>,
+    /// The byte range of the host, without the brackets of an IPv6 host
+    pub host: Option<Range<usize>>,
+    /// The byte range of the port, after `:`
+    pub port: Option<Range<usize>>,
+    /// The byte range of the path and anything after it
+    pub path: Range<usize>,
+    /// A hash of the parts of the url that `Display` writes, to tell whether
+    /// they were changed since it was parsed
+    written: u64,
+}
+
+/// The hash of the parsed url is left out
+impl fmt::Debug for UrlSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UrlSource")
+            .field("input", &self.input)
+            .field("style", &self.style)
+            .field("scheme", &self.scheme)
+            .field("user", &self.user)
+            .field("token", &self.token)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+impl UrlSource {
+    /// Records the `input` that `parsed` was parsed from
+    pub(crate) fn new(input: &str, parsed: &GitUrl) -> UrlSource {
+        // Spans are found in the address given to the remote helper
+        let address = parsed
+            .helper
+            .as_ref()
+            .and_then(|helper| input.strip_prefix(helper.as_str())?.strip_prefix("::"))
+            .unwrap_or(input);
+        let offset = input.len() - address.len();
+        let shift = |range: Range<usize>| range.start + offset..range.end + offset;
+
+        let style = match &parsed.scheme {
+            Scheme::Unspecified => UrlStyle::Opaque,
+            _ if address.contains("://") => UrlStyle::Uri,
+            Scheme::Git if address.starts_with("git:") => UrlStyle::ShortGit,
+            Scheme::File | Scheme::GitFile if is_windows_path(address) => UrlStyle::WindowsPath,
+            Scheme::File | Scheme::GitFile => UrlStyle::LocalPath,
+            _ => UrlStyle::ScpLike,
+        };
+
+        let components = match style {
+            UrlStyle::Uri | UrlStyle::ScpLike => Components::of(address),
+            UrlStyle::ShortGit => Components::of_authority(address, "git:".len(), '/'),
+            _ => Components::local(address),
+        };
+        let non_empty = |range: Range<usize>| (!range.is_empty()).then(|| shift(range));
+
+        UrlSource {
+            input: input.to_string(),
+            style,
+            scheme: components.scheme.map(shift),
+            user: components.user.and_then(non_empty),
+            token: components.token.and_then(non_empty),
+            host: components.host.and_then(non_empty),
+            port: components.port.and_then(non_empty),
+            path: shift(components.path),
+            written: written_hash(parsed),
+        }
+    }
+
+    /// Returns the input if `url` has the parts it was parsed with
+    pub(crate) fn input_of(&self, url: &GitUrl) -> Option<&str> {
+        match self.written == written_hash(url) {
+            true => Some(&self.input),
+            false => None,
+        }
+    }
+}
+
+/// Hashes the parts of `url` that [`GitUrl::to_canonical_string`] writes
+fn written_hash(url: &GitUrl) -> u64 {
+    let mut hasher = Fnv1aHasher::default();
+    (
+        &url.helper,
+        &url.scheme,
+        url.scheme_prefix,
+        &url.auth_user,
+        &url.auth_token,
+        &url.host,
+        url.port,
+        &url.path,
+    )
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A path with a drive letter or `\` separators
+fn is_windows_path(path: &str) -> bool {
+    let drive = path.as_bytes().get(1) == Some(&b':')
+        && path.as_bytes().first().is_some_and(u8::is_ascii_alphabetic);
+    drive || path.contains('\\')
+}
+
+/// The byte ranges of the components of an unparsed url
+pub(crate) struct Components {
+    /// The scheme, before `://`
+    pub(crate) scheme: Option<Range<usize>>,
+    /// Where the user, host and port start, after `scheme://`
+    pub(crate) authority_start: usize,
+    pub(crate) user: Option<Range<usize>>,
+    pub(crate) token: Option<Range<usize>>,
+    pub(crate) host: Option<Range<usize>>,
+    pub(crate) port: Option<Range<usize>>,
+    pub(crate) path: Range<usize>,
+}
+
+impl Components {
+    /// Splits a url with a scheme, an scp-like url or a local path
+    pub(crate) fn of(input: &str) -> Components {
+        match input.find("://") {
+            Some(i) => Components {
+                scheme: Some(0..i),
+                ..Components::of_authority(input, i + 3, '/')
+            },
+            None if is_ssh_url(input) => Components::of_authority(input, 0, ':'),
+            None => Components::local(input),
+        }
+    }
+
+    /// Splits the user, host, port and path of a url whose authority starts at
+    /// `authority_start` and ends at `separator`, the `:` of an scp-like url or
+    /// the `/` that starts the path
+    fn of_authority(input: &str, authority_start: usize, separator: char) -> Components {
+        // The `:`s of an IPv6 host are not separators
+        let masked = host::mask_ipv6(input);
+
+        let authority_end = masked[authority_start..]
+            .find(separator)
+            .map_or(input.len(), |end| authority_start + end);
+        let path_start = match separator {
+            ':' => (authority_end + 1).min(input.len()),
+            _ => authority_end,
+        };
+
+        let (user, token, host_start) = match masked[authority_start..authority_end].rfind('@') {
+            Some(at) => {
+                let at = authority_start + at;
+                match masked[authority_start..at].find(':') {
+                    Some(colon) => (
+                        Some(authority_start..authority_start + colon),
+                        Some(authority_start + colon + 1..at),
+                        at + 1,
+                    ),
+                    None => (Some(authority_start..at), None, at + 1),
+                }
+            }
+            None => (None, None, authority_start),
+        };
+        let (host, port) = match masked[host_start..authority_end].find(':') {
+            Some(colon) => (
+                host_start..host_start + colon,
+                Some(host_start + colon + 1..authority_end),
+            ),
+            None => (host_start..authority_end, None),
+        };
+        // IPv6 hosts are recorded without their brackets
+        let host = match input[host.clone()].starts_with('[') && input[host.clone()].ends_with(']')
+        {
+            true => host.start + 1..host.end - 1,
+            false => host,
+        };
+
+        Components {
+            scheme: None,
+            authority_start,
+            user,
+            token,
+            host: Some(host),
+            port,
+            path: path_start..input.len(),
+        }
+    }
+
+    /// A local path, which is all path
+    fn local(input: &str) -> Components {
+        Components {
+            scheme: None,
+            authority_start: 0,
+            user: None,
+            token: None,
+            host: None,
+            port: None,
+            path: 0..input.len(),
+        }
+    }
+}
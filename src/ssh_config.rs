@@ -0,0 +1,364 @@
+use crate::{GitUrl, GitUrlParseError, Scheme};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::fs;
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+
+/// ssh gives up on nested `Include`s at the same depth
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// SshConfig resolves host aliases the way `ssh` does, from the `Host` blocks
+/// of an ssh_config file such as `~/.ssh/config`
+///
+/// Only `HostName`, `User` and `Port` are read. As in ssh, the first value
+/// obtained for each of them wins, so specific `Host` blocks must come before
+/// wildcard ones. `Match` blocks other than `Match all` are never applied.
+#[derive(Debug, Default, Clone)]
+pub struct SshConfig {
+    blocks: Vec<Block>,
+}
+
+/// The options of a `Host` block, or of the lines before the first one
+#[derive(Debug, Clone)]
+struct Block {
+    /// `None` if the block applies to every host
+    patterns: Option<Vec<String>>,
+    host_name: Option<String>,
+    user: Option<String>,
+    port: Option<u16>,
+}
+
+/// The effective connection parameters of a host alias
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SshHost {
+    /// The host as written in the url
+    pub alias: String,
+    /// The host ssh connects to, which is the alias unless `HostName` is set
+    pub host_name: String,
+    /// The `User` of the alias
+    pub user: Option<String>,
+    /// The `Port` of the alias
+    pub port: Option<u16>,
+}
+
+/// An ssh url with its host alias resolved
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ResolvedSshUrl {
+    /// The host as written in the original url
+    pub alias: String,
+    /// The url ssh actually connects to, with the provider detected from the
+    /// effective host
+    pub url: GitUrl,
+}
+
+impl SshConfig {
+    /// Parses the text of an ssh_config file, ignoring `Include` directives
+    pub fn parse(text: &str) -> Result<Self, GitUrlParseError> {
+        Self::parse_with_includes(text, |_| Ok(Vec::new()))
+    }
+
+    /// Parses the text of an ssh_config file, calling `include` with each path
+    /// pattern of an `Include` directive to get the contents of the files it
+    /// matches, in order
+    pub fn parse_with_includes<F>(text: &str, mut include: F) -> Result<Self, GitUrlParseError>
+    where
+        F: FnMut(&str) -> Result<Vec<String>, GitUrlParseError>,
+    {
+        let mut config = SshConfig {
+            blocks: vec![Block::new(None)],
+        };
+        config.parse_into(text, &mut include, 0)?;
+        Ok(config)
+    }
+
+    /// Reads an ssh_config file, following `Include` directives
+    ///
+    /// Relative includes are found next to `path`, `~` is expanded from the
+    /// `HOME` environment variable, and `*` and `?` may be used in file names.
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, GitUrlParseError> {
+        let path = path.as_ref();
+        let text = read_file(path)?;
+        let dir = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        Self::parse_with_includes(&text, |pattern| {
+            include_files(&dir, pattern)?
+                .iter()
+                .map(|file| read_file(file))
+                .collect()
+        })
+    }
+
+    fn parse_into(
+        &mut self,
+        text: &str,
+        include: &mut dyn FnMut(&str) -> Result<Vec<String>, GitUrlParseError>,
+        depth: usize,
+    ) -> Result<(), GitUrlParseError> {
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let error = || GitUrlParseError::InvalidSshConfig(line_number);
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (keyword, args) = split_keyword(line).ok_or_else(error)?;
+            let args = split_args(args).ok_or_else(error)?;
+
+            match keyword.to_ascii_lowercase().as_str() {
+                "host" => self.blocks.push(Block::new(Some(args))),
+                "match" => {
+                    let patterns = match args.as_slice() {
+                        [all] if all.eq_ignore_ascii_case("all") => None,
+                        _ => Some(Vec::new()),
+                    };
+                    self.blocks.push(Block::new(patterns));
+                }
+                "include" => {
+                    if depth >= MAX_INCLUDE_DEPTH {
+                        return Err(error());
+                    }
+                    let outer = self.blocks.len();
+                    for pattern in &args {
+                        for text in include(pattern)? {
+                            self.parse_into(&text, include, depth + 1)?;
+                        }
+                    }
+                    // The rest of the outer block applies to its own patterns
+                    // again, not to the last block of the included files
+                    if self.blocks.len() > outer {
+                        let patterns = self.blocks[outer - 1].patterns.clone();
+                        self.blocks.push(Block::new(patterns));
+                    }
+                }
+                option => {
+                    let value = args.into_iter().next().ok_or_else(error)?;
+                    let block = self.blocks.last_mut().expect("There is always a block");
+                    match option {
+                        "hostname" => {
+                            block.host_name.get_or_insert(value);
+                        }
+                        "user" => {
+                            block.user.get_or_insert(value);
+                        }
+                        "port" => {
+                            let port = value.parse().map_err(|_| error())?;
+                            block.port.get_or_insert(port);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the effective host name, user and port of `alias`
+    pub fn lookup(&self, alias: &str) -> SshHost {
+        let mut host = SshHost {
+            alias: alias.to_string(),
+            host_name: alias.to_string(),
+            user: None,
+            port: None,
+        };
+        let mut host_name = None;
+        for block in self.blocks.iter().filter(|b| b.matches(alias)) {
+            host_name = host_name.or(block.host_name.as_deref());
+            host.user = host.user.or(block.user.clone());
+            host.port = host.port.or(block.port);
+        }
+        if let Some(host_name) = host_name {
+            host.host_name = expand_tokens(host_name, alias);
+        }
+        host
+    }
+
+    /// Returns the url ssh connects to for an ssh `url`, keeping the alias
+    ///
+    /// A user or port in `url` takes precedence over the config, as it does on
+    /// the ssh command line. Urls of other schemes are returned as they are.
+    pub fn resolve(&self, url: &GitUrl) -> Result<ResolvedSshUrl, GitUrlParseError> {
+        let alias = url
+            .host
+            .clone()
+            .ok_or(GitUrlParseError::UnsupportedUrlHostFormat)?;
+        if !matches!(url.scheme.transport(), Scheme::Ssh | Scheme::Sftp) {
+            return Ok(ResolvedSshUrl {
+                alias,
+                url: url.clone(),
+            });
+        }
+
+        let host = self.lookup(&alias);
+        let port = url.port.or(host.port.filter(|port| *port != 22));
+        let effective = GitUrl {
+            host: Some(host.host_name.to_ascii_lowercase()),
+            auth_user: url.auth_user.clone().or(host.user),
+            port,
+            // scp-like urls can't carry a port
+            scheme_prefix: url.scheme_prefix || port.is_some(),
+            ..url.clone()
+        };
+
+        // Parse again to detect the provider and layout of the effective host
+        Ok(ResolvedSshUrl {
+            alias,
+            url: GitUrl::parse(&effective.to_string())?,
+        })
+    }
+}
+
+impl Block {
+    fn new(patterns: Option<Vec<String>>) -> Self {
+        Block {
+            patterns,
+            host_name: None,
+            user: None,
+            port: None,
+        }
+    }
+
+    /// A block applies if any pattern matches and no negated pattern does
+    fn matches(&self, alias: &str) -> bool {
+        let Some(patterns) = &self.patterns else {
+            return true;
+        };
+        let alias = alias.to_ascii_lowercase();
+        let mut matched = false;
+        for pattern in patterns {
+            let pattern = pattern.to_ascii_lowercase();
+            match pattern.strip_prefix('!') {
+                Some(negated) if wildcard_match(negated, &alias) => return false,
+                Some(_) => {}
+                None => matched |= wildcard_match(&pattern, &alias),
+            }
+        }
+        matched
+    }
+}
+
+/// Splits `Keyword args` or `Keyword=args`
+fn split_keyword(line: &str) -> Option<(&str, &str)> {
+    let end = line.find(|c: char| c.is_whitespace() || c == '=')?;
+    let (keyword, rest) = line.split_at(end);
+    let rest = rest.trim_start();
+    let rest = rest.strip_prefix('=').unwrap_or(rest).trim_start();
+    Some((keyword, rest))
+}
+
+/// Splits arguments on whitespace, keeping double-quoted ones together
+fn split_args(args: &str) -> Option<Vec<String>> {
+    let mut result = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quoted = false;
+    for c in args.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                in_arg = true;
+            }
+            c if c.is_whitespace() && !quoted => {
+                if in_arg {
+                    result.push(core::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            c => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+    if quoted {
+        return None;
+    }
+    if in_arg {
+        result.push(current);
+    }
+    Some(result)
+}
+
+/// Matches `*` and `?` wildcards
+fn wildcard_match(pattern: &str, s: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let s: Vec<char> = s.chars().collect();
+    let (mut p, mut i) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while i < s.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, i));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == s[i] => {
+                p += 1;
+                i += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    i = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Expands the `%h` and `%%` tokens of `HostName`
+fn expand_tokens(host_name: &str, alias: &str) -> String {
+    host_name
+        .replace("%%", "\0")
+        .replace("%h", alias)
+        .replace('\0', "%")
+}
+
+#[cfg(feature = "std")]
+fn read_file(path: &Path) -> Result<String, GitUrlParseError> {
+    fs::read_to_string(path)
+        .map_err(|e| GitUrlParseError::ReadFailed(path.display().to_string(), e.to_string()))
+}
+
+/// Returns the files matched by the pattern of an `Include`, sorted by name
+#[cfg(feature = "std")]
+fn include_files(dir: &Path, pattern: &str) -> Result<Vec<PathBuf>, GitUrlParseError> {
+    let path = match pattern.strip_prefix("~/") {
+        Some(rest) => match std::env::var_os("HOME") {
+            Some(home) => Path::new(&home).join(rest),
+            None => return Ok(Vec::new()),
+        },
+        None => dir.join(pattern),
+    };
+
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    if !file_name.contains(['*', '?']) {
+        // ssh skips included files that don't exist
+        return Ok(match path.is_file() {
+            true => vec![path],
+            false => Vec::new(),
+        });
+    }
+
+    let parent = path.parent().unwrap_or(Path::new(""));
+    let mut files: Vec<PathBuf> = match fs::read_dir(parent) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| wildcard_match(&file_name, &entry.file_name().to_string_lossy()))
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,163 @@
+use crate::config::parse_config;
+use crate::{GitUrl, GitUrlParseError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// A submodule declared in a `.gitmodules` file
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Submodule {
+    /// The name of the `[submodule "name"]` section
+    pub name: String,
+    /// The path of the submodule within the superproject
+    pub path: String,
+    /// The branch followed by `git submodule update --remote`
+    pub branch: Option<String>,
+    /// The submodule's url, resolved against the superproject's remote if relative
+    pub url: GitUrl,
+}
+
+impl Submodule {
+    /// Parses the submodules of a `.gitmodules` file, in the order they are declared
+    ///
+    /// Relative urls such as `../lib.git` are resolved against `remote`, the url
+    /// of the superproject's default remote, with [`GitUrl::join`]. For a
+    /// superproject without a remote, git resolves them against its own path.
+    pub fn parse_gitmodules(
+        text: &str,
+        remote: &GitUrl,
+    ) -> Result<Vec<Submodule>, GitUrlParseError> {
+        let mut sections: Vec<Section> = Vec::new();
+
+        for entry in parse_config(text)? {
+            let Some(name) = entry.subsection.filter(|_| entry.section == "submodule") else {
+                continue;
+            };
+            let index = match sections.iter().position(|s| s.name == name) {
+                Some(index) => index,
+                None => {
+                    sections.push(Section {
+                        name,
+                        line: entry.line,
+                        ..Default::default()
+                    });
+                    sections.len() - 1
+                }
+            };
+            // Later values override earlier ones, as in git
+            let section = &mut sections[index];
+            match entry.key.as_str() {
+                "path" => section.path = entry.value,
+                "branch" => section.branch = entry.value,
+                "url" => section.url = entry.value,
+                _ => {}
+            }
+        }
+
+        sections
+            .into_iter()
+            .map(|section| match (section.path, section.url) {
+                (Some(path), Some(url)) => Ok(Submodule {
+                    name: section.name,
+                    path,
+                    branch: section.branch,
+                    url: remote.join(&url)?,
+                }),
+                _ => Err(GitUrlParseError::InvalidConfig(section.line)),
+            })
+            .collect()
+    }
+}
+
+/// The keys of a `[submodule "name"]` section
+#[derive(Default)]
+struct Section {
+    name: String,
+    path: Option<String>,
+    branch: Option<String>,
+    url: Option<String>,
+    /// The line of the section's first key
+    line: usize,
+}
+
+impl GitUrl {
+    /// Resolves a submodule url relative to this url, the way git does
+    ///
+    /// Each leading `../` removes the last path component of this url, and what
+    /// remains of `relative` is appended. For scp-like urls, going up past the
+    /// first path component keeps the `:`, so `git@host:org/app.git` joined with
+    /// `../../other/lib.git` is `git@host:other/lib.git`. Urls not starting with
+    /// `./` or `../` are parsed as they are.
+    ///
+    /// ```
+    /// use git_url_parse::GitUrl;
+    ///
+    /// let remote = GitUrl::parse("git@github.com:org/app.git").unwrap();
+    /// let lib = remote.join("../lib.git").unwrap();
+    /// assert_eq!(lib.to_string(), "git@github.com:org/lib.git");
+    /// ```
+    pub fn join(&self, relative: &str) -> Result<GitUrl, GitUrlParseError> {
+        GitUrl::parse(&relative_url(&self.to_string(), relative)?)
+    }
+}
+
+/// Follows `relative_url()` in git's `remote.c`, except that going above the
+/// root of `remote` is an error instead of producing a local path
+fn relative_url(remote: &str, url: &str) -> Result<String, GitUrlParseError> {
+    if !(url.starts_with("./") || url.starts_with("../")) {
+        return Ok(url.to_string());
+    }
+
+    let mut base = remote.strip_suffix('/').unwrap_or(remote).to_string();
+    // A local path without a ':' before the first '/'
+    let is_relative = match (base.find(':'), base.find('/')) {
+        (Some(colon), Some(slash)) => slash < colon && !base.starts_with('/'),
+        (Some(_), None) => false,
+        (None, _) => !base.starts_with('/'),
+    };
+    if is_relative && !(base.starts_with("./") || base.starts_with("../")) {
+        base.insert_str(0, "./");
+    }
+
+    let above_root = || GitUrlParseError::RelativeUrlAboveRoot(remote.to_string());
+    let mut rest = url;
+    let mut colon_separated = false;
+    loop {
+        if let Some(stripped) = rest.strip_prefix("../") {
+            rest = stripped;
+            match (base.rfind('/'), base.rfind(':')) {
+                // Never cut into the `//` of `scheme://`
+                (Some(slash), _)
+                    if base[..slash].ends_with(":/") || base[..slash].ends_with(':') =>
+                {
+                    return Err(above_root());
+                }
+                (Some(slash), _) if slash > 0 => base.truncate(slash),
+                (_, Some(colon)) => {
+                    base.truncate(colon);
+                    colon_separated = true;
+                }
+                _ => return Err(above_root()),
+            }
+        } else if let Some(stripped) = rest.strip_prefix("./") {
+            rest = stripped;
+        } else {
+            break;
+        }
+    }
+
+    let separator = match colon_separated {
+        true => ':',
+        false => '/',
+    };
+    let joined = format!(
+        "{}{}{}",
+        base,
+        separator,
+        rest.strip_suffix('/').unwrap_or(rest)
+    );
+    Ok(match joined.strip_prefix("./") {
+        Some(joined) => joined.to_string(),
+        None => joined,
+    })
+}
@@ -0,0 +1,146 @@
+use crate::web::encode_query;
+use crate::{GitUrl, GitUrlParseError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+use percent_encoding::percent_decode_str;
+
+/// TerraformSource is a Terraform module source fetched with git, such as
+/// `git::https://example.com/net.git//modules/vpc?ref=v1.2.0` or
+/// `git@github.com:org/mod.git`
+///
+/// Sources are fetched with git when they have the `git::` forced getter
+/// prefix, are scp-like ssh urls for the `git` user, or are on `github.com` or
+/// `bitbucket.org` without a scheme.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TerraformSource {
+    /// The repository of the module
+    pub repo: GitUrl,
+    /// The directory of the module within the repository, after `//`
+    pub subdir: Option<String>,
+    /// The branch, tag or commit to check out, from `ref`
+    pub git_ref: Option<String>,
+    /// The depth of a shallow clone, from `depth`
+    pub depth: Option<u32>,
+    /// The base64 encoded ssh private key used to clone, from `sshkey`
+    pub sshkey: Option<String>,
+    /// Other query parameters, which are passed along to the repository url
+    pub params: Vec<(String, String)>,
+}
+
+impl FromStr for TerraformSource {
+    type Err = GitUrlParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TerraformSource::parse(s)
+    }
+}
+
+impl TerraformSource {
+    /// Returns a `Result<TerraformSource>` after splitting the forced getter,
+    /// subdirectory and query parameters off of `source`
+    pub fn parse(source: &str) -> Result<TerraformSource, GitUrlParseError> {
+        let source = source.trim();
+        let url = match split_getter(source) {
+            Some(("git", url)) => url,
+            Some((getter, _)) => {
+                return Err(GitUrlParseError::UnsupportedGetter(getter.to_string()))
+            }
+            None if source.starts_with("github.com/") || source.starts_with("bitbucket.org/") => {
+                source
+            }
+            None if source.starts_with("git@") => source,
+            None => return Err(GitUrlParseError::UnexpectedFormat),
+        };
+
+        let (url, query) = match url.split_once('?') {
+            Some((url, query)) => (url, Some(query)),
+            None => (url, None),
+        };
+        let (url, subdir) = split_subdir(url);
+        let url = match url.starts_with("github.com/") || url.starts_with("bitbucket.org/") {
+            true => format!("https://{}", url),
+            false => url.to_string(),
+        };
+
+        let mut terraform_source = TerraformSource {
+            repo: GitUrl::parse(&url)?,
+            subdir: subdir.map(|s| s.to_string()),
+            git_ref: None,
+            depth: None,
+            sshkey: None,
+            params: Vec::new(),
+        };
+        for pair in query.into_iter().flat_map(|q| q.split('&')) {
+            if pair.is_empty() {
+                continue;
+            }
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = percent_decode_str(value).decode_utf8_lossy().to_string();
+            match key {
+                "ref" => terraform_source.git_ref = Some(value),
+                "depth" => {
+                    terraform_source.depth =
+                        Some(value.parse().map_err(|_| {
+                            GitUrlParseError::InvalidSourceParameter(key.to_string())
+                        })?)
+                }
+                "sshkey" => terraform_source.sshkey = Some(value),
+                _ => terraform_source.params.push((key.to_string(), value)),
+            }
+        }
+        Ok(terraform_source)
+    }
+}
+
+/// Formats the source with the `git::` forced getter, so it is fetched with git
+/// wherever the repository is hosted
+impl fmt::Display for TerraformSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "git::{}", self.repo)?;
+        if let Some(subdir) = &self.subdir {
+            write!(f, "//{}", subdir)?;
+        }
+
+        let mut params = Vec::new();
+        if let Some(git_ref) = &self.git_ref {
+            params.push(format!("ref={}", encode_query(git_ref)));
+        }
+        if let Some(depth) = self.depth {
+            params.push(format!("depth={}", depth));
+        }
+        if let Some(sshkey) = &self.sshkey {
+            params.push(format!("sshkey={}", encode_query(sshkey)));
+        }
+        for (key, value) in &self.params {
+            params.push(format!("{}={}", key, encode_query(value)));
+        }
+        if !params.is_empty() {
+            write!(f, "?{}", params.join("&"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits a forced getter such as `git::` off of `source`
+fn split_getter(source: &str) -> Option<(&str, &str)> {
+    let (getter, url) = source.split_once("::")?;
+    match !getter.is_empty() && getter.chars().all(|c| c.is_ascii_alphanumeric()) {
+        true => Some((getter, url)),
+        false => None,
+    }
+}
+
+/// Splits the subdirectory after the first `//` that isn't part of `://`
+fn split_subdir(url: &str) -> (&str, Option<&str>) {
+    let start = url.find("://").map_or(0, |i| i + 3);
+    match url[start..].find("//") {
+        Some(i) => {
+            let subdir = url[start + i + 2..].trim_matches('/');
+            (&url[..start + i], (!subdir.is_empty()).then_some(subdir))
+        }
+        None => (url, None),
+    }
+}
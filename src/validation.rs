@@ -0,0 +1,444 @@
+use crate::{GitUrl, Provider};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use percent_encoding::percent_decode_str;
+use strum::Display;
+
+/// The parts of a repo path checked by [`GitUrl::validate`]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Display)]
+#[strum(serialize_all = "kebab_case")]
+pub enum NameComponent {
+    /// The organization, or top-level group
+    Organization,
+    /// One of the subgroups between the organization and the owner
+    Subgroup,
+    /// The owner/account/project name
+    Owner,
+    /// The name of the repo
+    Name,
+}
+
+/// How a name breaks the rules of its host
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ViolationKind {
+    /// The name is empty
+    Empty,
+    /// The name is longer than the given number of characters
+    TooLong(usize),
+    /// The name contains a character the host doesn't allow
+    InvalidCharacter(char),
+    /// The name starts with a character the host doesn't allow there
+    InvalidStart(char),
+    /// The name ends with a character the host doesn't allow there
+    InvalidEnd(char),
+    /// The character appears twice in a row, which the host doesn't allow
+    RepeatedCharacter(char),
+    /// The name ends with a suffix the host reserves, such as GitLab's `.atom`
+    InvalidSuffix(&'static str),
+    /// The name is used by the host itself, such as GitHub's `settings`
+    Reserved,
+    /// The name has uppercase letters where the host only allows lowercase
+    Uppercase,
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ViolationKind::Empty => write!(f, "is empty"),
+            ViolationKind::TooLong(max) => write!(f, "is longer than {} characters", max),
+            ViolationKind::InvalidCharacter(c) => write!(f, "contains `{}`", c),
+            ViolationKind::InvalidStart(c) => write!(f, "starts with `{}`", c),
+            ViolationKind::InvalidEnd(c) => write!(f, "ends with `{}`", c),
+            ViolationKind::RepeatedCharacter(c) => write!(f, "repeats `{}`", c),
+            ViolationKind::InvalidSuffix(suffix) => write!(f, "ends with `{}`", suffix),
+            ViolationKind::Reserved => write!(f, "is reserved"),
+            ViolationKind::Uppercase => write!(f, "is not lowercase"),
+        }
+    }
+}
+
+/// A name in the repo path that its host would reject
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Violation {
+    /// The part of the path the name is
+    pub component: NameComponent,
+    /// The name, percent-decoded
+    pub value: String,
+    /// The rule the name breaks
+    pub kind: ViolationKind,
+}
+
+/// Formats the violation as a message, e.g. ``owner `settings` is reserved``
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} `{}` {}", self.component, self.value, self.kind)
+    }
+}
+
+/// ProviderRules selects the naming rules [`GitUrl::validate`] checks
+///
+/// The rules of the url's `provider` are used unless another provider is
+/// given. GitHub, GitLab, Azure DevOps and Bitbucket have rules; other
+/// providers only check the names reserved with [`ProviderRules::reserve`].
+#[derive(Debug, Clone, Default)]
+pub struct ProviderRules {
+    provider: Option<Provider>,
+    reserved: Vec<String>,
+}
+
+impl ProviderRules {
+    /// Returns the rules of the url's provider
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check the rules of `provider`, such as the forge a repo will be
+    /// mirrored to, instead of those of the url's provider
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Reject `name` anywhere in the path, compared case-insensitively
+    pub fn reserve(mut self, name: &str) -> Self {
+        self.reserved.push(name.to_string());
+        self
+    }
+}
+
+impl GitUrl {
+    /// Returns the organization, subgroup, owner and repo names that break the
+    /// naming rules of the host, in path order
+    pub fn validate(&self, rules: &ProviderRules) -> Vec<Violation> {
+        let provider = rules.provider.unwrap_or(self.provider);
+
+        let mut names = Vec::new();
+        if let Some(organization) = &self.organization {
+            names.push((NameComponent::Organization, organization.as_str()));
+        }
+        for subgroup in self.subgroups.iter().flat_map(|s| s.split('/')) {
+            names.push((NameComponent::Subgroup, subgroup));
+        }
+        if let Some(owner) = &self.owner {
+            names.push((NameComponent::Owner, owner.as_str()));
+        }
+        names.push((NameComponent::Name, self.name.as_str()));
+
+        let mut violations = Vec::new();
+        for (i, (component, name)) in names.into_iter().enumerate() {
+            let value = percent_decode_str(name).decode_utf8_lossy().to_string();
+            let kinds = match rules
+                .reserved
+                .iter()
+                .any(|r| r.eq_ignore_ascii_case(&value))
+            {
+                true => vec![ViolationKind::Reserved],
+                false => match name_rule(provider, component, &value, i == 0) {
+                    Some(rule) => rule.check(&value),
+                    None => Vec::new(),
+                },
+            };
+            violations.extend(kinds.into_iter().map(|kind| Violation {
+                component,
+                value: value.clone(),
+                kind,
+            }));
+        }
+        violations
+    }
+}
+
+/// The rules a host has for one kind of name
+struct NameRule {
+    max_len: usize,
+    allowed: fn(char) -> bool,
+    start: fn(char) -> bool,
+    end: fn(char) -> bool,
+    lowercase: bool,
+    no_repeat: Option<char>,
+    suffixes: &'static [&'static str],
+    reserved: &'static [&'static str],
+}
+
+impl NameRule {
+    /// A name of up to `max_len` characters, with only `allowed` characters
+    const fn new(max_len: usize, allowed: fn(char) -> bool) -> NameRule {
+        NameRule {
+            max_len,
+            allowed,
+            start: |_| true,
+            end: |_| true,
+            lowercase: false,
+            no_repeat: None,
+            suffixes: &[],
+            reserved: &[],
+        }
+    }
+
+    fn check(&self, name: &str) -> Vec<ViolationKind> {
+        // Whatever else is wrong with a reserved name, it can't be used
+        if self.reserved.iter().any(|r| r.eq_ignore_ascii_case(name)) {
+            return vec![ViolationKind::Reserved];
+        }
+        let (first, last) = match (name.chars().next(), name.chars().next_back()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return vec![ViolationKind::Empty],
+        };
+
+        let mut kinds = Vec::new();
+        if name.chars().count() > self.max_len {
+            kinds.push(ViolationKind::TooLong(self.max_len));
+        }
+        if let Some(c) = name.chars().find(|c| !(self.allowed)(*c)) {
+            kinds.push(ViolationKind::InvalidCharacter(c));
+        }
+        if self.lowercase && name.chars().any(char::is_uppercase) {
+            kinds.push(ViolationKind::Uppercase);
+        }
+        // Characters that aren't allowed at all were reported above
+        if (self.allowed)(first) && !(self.start)(first) {
+            kinds.push(ViolationKind::InvalidStart(first));
+        }
+        if (self.allowed)(last) && !(self.end)(last) {
+            kinds.push(ViolationKind::InvalidEnd(last));
+        }
+        if let Some(c) = self.no_repeat {
+            if name
+                .chars()
+                .zip(name.chars().skip(1))
+                .any(|pair| pair == (c, c))
+            {
+                kinds.push(ViolationKind::RepeatedCharacter(c));
+            }
+        }
+        let lower = name.to_lowercase();
+        if let Some(suffix) = self.suffixes.iter().find(|suffix| lower.ends_with(*suffix)) {
+            kinds.push(ViolationKind::InvalidSuffix(suffix));
+        }
+        kinds
+    }
+}
+
+/// Returns the rule for a `name` of `provider`, if it has one
+fn name_rule(
+    provider: Provider,
+    component: NameComponent,
+    name: &str,
+    top_level: bool,
+) -> Option<NameRule> {
+    use NameComponent::*;
+
+    match (provider, component) {
+        (Provider::GitHub, Organization | Owner) => Some(GITHUB_ACCOUNT),
+        (Provider::GitHub, Name) => Some(GITHUB_REPO),
+        (Provider::GitLab, _) if top_level => Some(GITLAB_TOP_LEVEL),
+        (Provider::GitLab, _) => Some(GITLAB_PATH),
+        (Provider::AzureDevOps, Organization) => Some(AZURE_ORGANIZATION),
+        (Provider::AzureDevOps, Owner) => Some(AZURE_PROJECT),
+        (Provider::AzureDevOps, Name) => Some(AZURE_REPO),
+        (Provider::BitbucketCloud, Owner) => Some(BITBUCKET_WORKSPACE),
+        (Provider::BitbucketCloud, Name) => Some(BITBUCKET_CLOUD_REPO),
+        // Personal repos are under `~user` instead of a project key
+        (Provider::BitbucketServer, Owner) if !name.starts_with('~') => Some(BITBUCKET_PROJECT_KEY),
+        (Provider::BitbucketServer, Name) => Some(BITBUCKET_SERVER_REPO),
+        _ => None,
+    }
+}
+
+/// GitHub users and organizations: single hyphens between letters and digits
+const GITHUB_ACCOUNT: NameRule = NameRule {
+    start: |c| c.is_ascii_alphanumeric(),
+    end: |c| c.is_ascii_alphanumeric(),
+    no_repeat: Some('-'),
+    reserved: &[
+        "about",
+        "account",
+        "admin",
+        "api",
+        "apps",
+        "blog",
+        "business",
+        "collections",
+        "contact",
+        "dashboard",
+        "enterprise",
+        "explore",
+        "features",
+        "gist",
+        "issues",
+        "join",
+        "login",
+        "logout",
+        "marketplace",
+        "new",
+        "notifications",
+        "organizations",
+        "orgs",
+        "pricing",
+        "pulls",
+        "search",
+        "security",
+        "settings",
+        "site",
+        "sponsors",
+        "topics",
+        "trending",
+    ],
+    ..NameRule::new(39, |c| c.is_ascii_alphanumeric() || c == '-')
+};
+
+const GITHUB_REPO: NameRule = NameRule {
+    reserved: &[".", ".."],
+    ..NameRule::new(100, |c| {
+        c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+    })
+};
+
+/// GitLab group, user and project paths
+const GITLAB_PATH: NameRule = NameRule {
+    start: |c| c.is_ascii_alphanumeric() || c == '_',
+    suffixes: &[".git", ".atom"],
+    // `-` separates the routes of a group or project from its path
+    reserved: &["-"],
+    ..NameRule::new(255, |c| {
+        c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+    })
+};
+
+/// GitLab namespaces directly below the root, which can't take the name of a
+/// route of the instance
+const GITLAB_TOP_LEVEL: NameRule = NameRule {
+    reserved: &[
+        "-",
+        ".well-known",
+        "404.html",
+        "422.html",
+        "500.html",
+        "502.html",
+        "503.html",
+        "admin",
+        "api",
+        "apple-touch-icon.png",
+        "assets",
+        "dashboard",
+        "deploy.html",
+        "explore",
+        "favicon.ico",
+        "favicon.png",
+        "files",
+        "groups",
+        "health_check",
+        "help",
+        "import",
+        "jwt",
+        "login",
+        "oauth",
+        "profile",
+        "projects",
+        "public",
+        "robots.txt",
+        "s",
+        "search",
+        "sitemap",
+        "sitemap.xml",
+        "sitemap.xml.gz",
+        "slash-command-logo.png",
+        "snippets",
+        "unsubscribes",
+        "uploads",
+        "users",
+        "v2",
+    ],
+    ..GITLAB_PATH
+};
+
+const AZURE_ORGANIZATION: NameRule = NameRule {
+    start: |c| c.is_ascii_alphanumeric(),
+    end: |c| c.is_ascii_alphanumeric(),
+    ..NameRule::new(50, |c| c.is_ascii_alphanumeric() || c == '-')
+};
+
+/// Azure DevOps projects, which may contain spaces
+const AZURE_PROJECT: NameRule = NameRule {
+    start: |c| !matches!(c, '_' | '.'),
+    end: |c| c != '.',
+    reserved: AZURE_RESERVED,
+    ..NameRule::new(64, azure_character)
+};
+
+const AZURE_REPO: NameRule = NameRule {
+    start: |c| !matches!(c, '_' | '.'),
+    end: |c| c != '.',
+    reserved: AZURE_RESERVED,
+    ..NameRule::new(64, azure_character)
+};
+
+/// Names Azure DevOps Server and IIS use for themselves
+const AZURE_RESERVED: &[&str] = &[
+    "App_Browsers",
+    "App_code",
+    "App_Data",
+    "App_GlobalResources",
+    "App_LocalResources",
+    "App_Themes",
+    "App_WebResources",
+    "AUX",
+    "bin",
+    "COM1",
+    "COM2",
+    "COM3",
+    "COM4",
+    "COM5",
+    "COM6",
+    "COM7",
+    "COM8",
+    "COM9",
+    "CON",
+    "DefaultCollection",
+    "LPT1",
+    "LPT2",
+    "LPT3",
+    "LPT4",
+    "LPT5",
+    "LPT6",
+    "LPT7",
+    "LPT8",
+    "LPT9",
+    "NUL",
+    "PRN",
+    "SERVER",
+    "SignalR",
+    "Web",
+    "web.config",
+];
+
+fn azure_character(c: char) -> bool {
+    !c.is_control() && !"\\/:*?\"'<>;#${},+=[]|".contains(c)
+}
+
+/// Bitbucket Cloud workspace ids, which are lowercase
+const BITBUCKET_WORKSPACE: NameRule = NameRule {
+    lowercase: true,
+    ..NameRule::new(62, |c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+};
+
+/// Bitbucket repo slugs, which are lowercase
+const BITBUCKET_CLOUD_REPO: NameRule = NameRule {
+    lowercase: true,
+    ..NameRule::new(62, |c| {
+        c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+    })
+};
+
+const BITBUCKET_SERVER_REPO: NameRule = NameRule {
+    max_len: 128,
+    ..BITBUCKET_CLOUD_REPO
+};
+
+/// Bitbucket Server project keys, which start with a letter
+const BITBUCKET_PROJECT_KEY: NameRule = NameRule {
+    start: |c| c.is_ascii_alphabetic(),
+    ..NameRule::new(128, |c| c.is_ascii_alphanumeric() || c == '_')
+};
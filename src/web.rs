@@ -0,0 +1,240 @@
+use crate::convert::{https_host, tfs_prefix};
+use crate::{GitUrl, LineRange, Provider, Scheme};
+use alloc::format;
+use alloc::string::{String, ToString};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+/// Characters escaped in refs and file paths, which keeps `/` as a separator
+const PATH: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// Characters escaped in query parameter values
+const QUERY: &AsciiSet = &PATH.add(b'&').add(b'=').add(b'+');
+
+fn encode_path(s: &str) -> String {
+    utf8_percent_encode(s.trim_start_matches('/'), PATH).to_string()
+}
+
+pub(crate) fn encode_query(s: &str) -> String {
+    utf8_percent_encode(s, QUERY).to_string()
+}
+
+/// A full sha-1 or sha-256 commit id, as opposed to a branch or tag name
+fn is_commit_id(git_ref: &str) -> bool {
+    (git_ref.len() == 40 || git_ref.len() == 64) && git_ref.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Build browser urls for a repository from its `GitUrl`
+///
+/// These follow the conventions of the detected `provider`, and work the same
+/// for ssh and https urls of a repository. They return `None` for local paths and
+/// for hosts whose web interface is unknown, i.e. [`Provider::Generic`].
+impl GitUrl {
+    /// Returns the url of the repository's page in the browser
+    pub fn web_url(&self) -> Option<String> {
+        if self.provider == Provider::Generic {
+            return None;
+        }
+        let host = https_host(self)?;
+        let host = host.as_str();
+
+        let scheme = match self.scheme.transport() {
+            Scheme::Http => "http",
+            _ => "https",
+        };
+        let port = match (self.scheme.transport(), self.port) {
+            (Scheme::Http | Scheme::Https, Some(port)) => format!(":{}", port),
+            _ => String::new(),
+        };
+
+        let repo_path = match self.provider {
+            Provider::AzureDevOps => {
+                let organization = self.organization.as_deref()?;
+                let project = self.owner.as_deref().unwrap_or(&self.name);
+                let repo = format!("{}/_git/{}", project, self.name);
+
+                return Some(match host {
+                    "dev.azure.com" => format!("https://dev.azure.com/{}/{}", organization, repo),
+                    _ if host.ends_with(".visualstudio.com") => {
+                        format!("https://{}/{}", host, repo)
+                    }
+                    _ => format!(
+                        "{}://{}{}/{}{}/{}",
+                        scheme,
+                        host,
+                        port,
+                        tfs_prefix(self),
+                        organization,
+                        repo
+                    ),
+                });
+            }
+            Provider::BitbucketServer => {
+                let key = self.owner.as_deref()?;
+                match key.strip_prefix('~') {
+                    Some(user) => format!("users/{}/repos/{}", user, self.name),
+                    None => format!("projects/{}/repos/{}", key, self.name),
+                }
+            }
+            Provider::Gerrit if !host.ends_with(".googlesource.com") => {
+                format!("plugins/gitiles/{}", self.fullname)
+            }
+            _ => self.fullname.clone(),
+        };
+
+        Some(format!("{}://{}{}/{}", scheme, host, port, repo_path))
+    }
+
+    /// Returns the url of a file at `git_ref` in the browser, optionally
+    /// highlighting `lines`
+    pub fn blob_url(&self, git_ref: &str, path: &str, lines: Option<LineRange>) -> Option<String> {
+        let web_url = self.web_url()?;
+        let (r, p) = (encode_path(git_ref), encode_path(path));
+
+        let (url, anchor) = match self.provider {
+            Provider::GitHub => (
+                format!("{}/blob/{}/{}", web_url, r, p),
+                lines.map(|l| match l.end {
+                    Some(end) => format!("L{}-L{}", l.start, end),
+                    None => format!("L{}", l.start),
+                }),
+            ),
+            Provider::GitLab => (
+                format!("{}/-/blob/{}/{}", web_url, r, p),
+                lines.map(|l| format!("L{}", l)),
+            ),
+            Provider::BitbucketCloud => (
+                format!("{}/src/{}/{}", web_url, r, p),
+                lines.map(|l| match l.end {
+                    Some(end) => format!("lines-{}:{}", l.start, end),
+                    None => format!("lines-{}", l.start),
+                }),
+            ),
+            Provider::BitbucketServer => (
+                format!("{}/browse/{}?at={}", web_url, p, encode_query(git_ref)),
+                lines.map(|l| l.to_string()),
+            ),
+            Provider::Gitea => {
+                let ref_kind = match is_commit_id(git_ref) {
+                    true => "commit",
+                    false => "branch",
+                };
+                (
+                    format!("{}/src/{}/{}/{}", web_url, ref_kind, r, p),
+                    lines.map(|l| match l.end {
+                        Some(end) => format!("L{}-L{}", l.start, end),
+                        None => format!("L{}", l.start),
+                    }),
+                )
+            }
+            Provider::AzureDevOps => {
+                let mut url = format!(
+                    "{}?path=/{}&version={}",
+                    web_url,
+                    encode_query(path.trim_start_matches('/')),
+                    azure_version(git_ref)
+                );
+                if let Some(l) = lines {
+                    url.push_str(&format!(
+                        "&line={}&lineEnd={}",
+                        l.start,
+                        l.end.unwrap_or(l.start)
+                    ));
+                }
+                (url, None)
+            }
+            Provider::SourceHut => (
+                format!("{}/tree/{}/item/{}", web_url, r, p),
+                lines.map(|l| format!("L{}", l)),
+            ),
+            Provider::Gerrit => (
+                format!("{}/+/{}/{}", web_url, r, p),
+                lines.map(|l| l.start.to_string()),
+            ),
+            Provider::Generic => return None,
+        };
+
+        Some(match anchor {
+            Some(anchor) => format!("{}#{}", url, anchor),
+            None => url,
+        })
+    }
+
+    /// Returns the url of the unrendered content of a file at `git_ref`
+    pub fn raw_url(&self, git_ref: &str, path: &str) -> Option<String> {
+        let web_url = self.web_url()?;
+        let (r, p) = (encode_path(git_ref), encode_path(path));
+
+        Some(match self.provider {
+            Provider::GitHub => match web_url.strip_prefix("https://github.com/") {
+                Some(repo) => format!("https://raw.githubusercontent.com/{}/{}/{}", repo, r, p),
+                None => format!("{}/raw/{}/{}", web_url, r, p),
+            },
+            Provider::GitLab => format!("{}/-/raw/{}/{}", web_url, r, p),
+            Provider::BitbucketCloud => format!("{}/raw/{}/{}", web_url, r, p),
+            Provider::BitbucketServer => {
+                format!("{}/raw/{}?at={}", web_url, p, encode_query(git_ref))
+            }
+            Provider::Gitea => {
+                let ref_kind = match is_commit_id(git_ref) {
+                    true => "commit",
+                    false => "branch",
+                };
+                format!("{}/raw/{}/{}/{}", web_url, ref_kind, r, p)
+            }
+            Provider::AzureDevOps => {
+                let (api_base, repo) = web_url.rsplit_once("/_git/")?;
+                let version_type = match is_commit_id(git_ref) {
+                    true => "commit",
+                    false => "branch",
+                };
+                format!(
+                    "{}/_apis/git/repositories/{}/items?path=/{}&versionDescriptor.version={}&versionDescriptor.versionType={}&download=true",
+                    api_base,
+                    repo,
+                    encode_query(path.trim_start_matches('/')),
+                    encode_query(git_ref),
+                    version_type
+                )
+            }
+            Provider::SourceHut => format!("{}/blob/{}/{}", web_url, r, p),
+            Provider::Gerrit => format!("{}/+/{}/{}?format=TEXT", web_url, r, p),
+            Provider::Generic => return None,
+        })
+    }
+
+    /// Returns the url of the commit `sha` in the browser
+    pub fn commit_url(&self, sha: &str) -> Option<String> {
+        let web_url = self.web_url()?;
+        let sha = encode_path(sha);
+
+        Some(match self.provider {
+            Provider::GitLab => format!("{}/-/commit/{}", web_url, sha),
+            Provider::BitbucketCloud | Provider::BitbucketServer => {
+                format!("{}/commits/{}", web_url, sha)
+            }
+            Provider::Gerrit => format!("{}/+/{}", web_url, sha),
+            Provider::Generic => return None,
+            _ => format!("{}/commit/{}", web_url, sha),
+        })
+    }
+}
+
+/// Azure DevOps prefixes versions with `GB` for branches, `GT` for tags and
+/// `GC` for commits. Tags can't be told apart from branches, so they are
+/// assumed to be branches.
+fn azure_version(git_ref: &str) -> String {
+    match is_commit_id(git_ref) {
+        true => format!("GC{}", git_ref),
+        false => format!("GB{}", encode_query(git_ref)),
+    }
+}
@@ -0,0 +1,132 @@
+use git_url_parse::*;
+
+fn assert_repo(test_url: &str, organization: &str, project: &str, name: &str) {
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.provider, Provider::AzureDevOps, "{}", test_url);
+    assert_eq!(
+        parsed.organization.as_deref(),
+        Some(organization),
+        "{}",
+        test_url
+    );
+    assert_eq!(parsed.owner.as_deref(), Some(project), "{}", test_url);
+    assert_eq!(parsed.subgroups, None, "{}", test_url);
+    assert_eq!(parsed.name, name, "{}", test_url);
+    assert_eq!(
+        parsed.fullname,
+        format!("{}/{}/{}", organization, project, name),
+        "{}",
+        test_url
+    );
+}
+
+#[test]
+fn dev_azure_com() {
+    assert_repo(
+        "https://dev.azure.com/CompanyName/ProjectName/_git/RepoName",
+        "CompanyName",
+        "ProjectName",
+        "RepoName",
+    );
+}
+
+#[test]
+fn dev_azure_com_without_project() {
+    assert_repo(
+        "https://dev.azure.com/CompanyName/_git/RepoName",
+        "CompanyName",
+        "RepoName",
+        "RepoName",
+    );
+}
+
+#[test]
+fn ssh_v3() {
+    assert_repo(
+        "git@ssh.dev.azure.com:v3/CompanyName/ProjectName/RepoName",
+        "CompanyName",
+        "ProjectName",
+        "RepoName",
+    );
+}
+
+#[test]
+fn visualstudio_com() {
+    assert_repo(
+        "https://CompanyName.visualstudio.com/ProjectName/_git/RepoName",
+        "companyname",
+        "ProjectName",
+        "RepoName",
+    );
+}
+
+#[test]
+fn visualstudio_com_default_collection() {
+    assert_repo(
+        "https://companyname.visualstudio.com/DefaultCollection/ProjectName/_git/RepoName",
+        "companyname",
+        "ProjectName",
+        "RepoName",
+    );
+}
+
+#[test]
+fn visualstudio_com_ssh() {
+    assert_repo(
+        "companyname@vs-ssh.visualstudio.com:v3/companyname/ProjectName/RepoName",
+        "companyname",
+        "ProjectName",
+        "RepoName",
+    );
+}
+
+#[test]
+fn tfs_collection() {
+    assert_repo(
+        "https://tfs.corp.example/tfs/Collection/ProjectName/_git/RepoName",
+        "Collection",
+        "ProjectName",
+        "RepoName",
+    );
+}
+
+#[test]
+fn tfs_ssh() {
+    assert_repo(
+        "ssh://tfs.corp.example:22/tfs/Collection/ProjectName/_ssh/RepoName",
+        "Collection",
+        "ProjectName",
+        "RepoName",
+    );
+}
+
+#[test]
+fn azure_devops_server_without_tfs_prefix() {
+    assert_repo(
+        "https://devops.corp.example/Collection/ProjectName/_git/RepoName",
+        "Collection",
+        "ProjectName",
+        "RepoName",
+    );
+}
+
+#[test]
+fn https_and_ssh_agree() {
+    let https =
+        GitUrl::parse("https://CompanyName@dev.azure.com/CompanyName/ProjectName/_git/RepoName")
+            .expect("URL parse failed");
+    let ssh = GitUrl::parse("git@ssh.dev.azure.com:v3/CompanyName/ProjectName/RepoName")
+        .expect("URL parse failed");
+
+    assert_eq!(https.organization, ssh.organization);
+    assert_eq!(https.owner, ssh.owner);
+    assert_eq!(https.name, ssh.name);
+    assert_eq!(https.fullname, ssh.fullname);
+}
+
+#[test]
+fn missing_repo_after_git() {
+    let e = GitUrl::parse("https://dev.azure.com/CompanyName/ProjectName/_git");
+    assert_eq!(e, Err(GitUrlParseError::UnexpectedFormat));
+}
@@ -0,0 +1,285 @@
+use git_url_parse::*;
+
+fn assert_round_trip(built: GitUrl) {
+    let parsed = GitUrl::parse(&built.to_string()).expect("URL parse failed");
+    assert_eq!(parsed, built);
+}
+
+#[test]
+fn https_with_auth() {
+    let built = GitUrl::builder()
+        .host("github.com")
+        .owner("owner")
+        .name("repo")
+        .auth_user("token")
+        .auth_token("x-oauth-basic")
+        .git_suffix(true)
+        .build()
+        .expect("Build failed");
+
+    let expected = GitUrl {
+        host: Some("github.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("owner".to_string()),
+        subgroups: None,
+        organization: None,
+        fullname: "owner/repo".to_string(),
+        helper: None,
+        scheme: Scheme::Https,
+        auth_user: Some("token".to_string()),
+        auth_token: Some("x-oauth-basic".to_string()),
+        port: None,
+        path: "/owner/repo.git".to_string(),
+        path_base: PathBase::Default,
+        git_suffix: true,
+        scheme_prefix: true,
+        provider: Provider::GitHub,
+        extra: None,
+        source: None,
+    };
+
+    assert_eq!(built, expected);
+    assert_round_trip(built);
+}
+
+#[test]
+fn ssh_with_port_and_subgroups() {
+    let built = GitUrl::builder()
+        .scheme(Scheme::Ssh)
+        .auth_user("git")
+        .host("gitlab.example.com")
+        .port(222)
+        .organization("org")
+        .subgroups("sub1/sub2")
+        .owner("project")
+        .name("repo")
+        .build()
+        .expect("Build failed");
+
+    assert_eq!(built.path, "org/sub1/sub2/project/repo");
+    assert_eq!(built.fullname, "org/sub1/sub2/project/repo");
+    assert_eq!(built.provider, Provider::GitLab);
+    assert_eq!(
+        built.to_string(),
+        "ssh://git@gitlab.example.com:222/org/sub1/sub2/project/repo"
+    );
+    assert_round_trip(built);
+}
+
+#[test]
+fn scp_like() {
+    let built = GitUrl::builder()
+        .scheme(Scheme::Ssh)
+        .scheme_prefix(false)
+        .auth_user("git")
+        .host("bitbucket.org")
+        .owner("owner")
+        .name("repo")
+        .git_suffix(true)
+        .build()
+        .expect("Build failed");
+
+    assert_eq!(built.to_string(), "git@bitbucket.org:owner/repo.git");
+    assert_round_trip(built);
+}
+
+#[test]
+fn file() {
+    let built = GitUrl::builder()
+        .scheme(Scheme::File)
+        .owner("srv")
+        .name("repo")
+        .git_suffix(true)
+        .build()
+        .expect("Build failed");
+
+    assert_eq!(built.owner, None);
+    assert_eq!(built.fullname, "repo");
+    assert_eq!(built.path, "/srv/repo.git");
+}
+
+#[test]
+fn provider_override() {
+    let built = GitUrl::builder()
+        .host("code.corp.example")
+        .owner("owner")
+        .name("repo")
+        .provider(Provider::Gitea)
+        .build()
+        .expect("Build failed");
+
+    assert_eq!(built.provider, Provider::Gitea);
+}
+
+#[test]
+fn rejects_impossible_combinations() {
+    assert_eq!(
+        GitUrl::builder()
+            .scheme(Scheme::File)
+            .auth_user("git")
+            .name("repo")
+            .build(),
+        Err(GitUrlBuildError::AuthNotSupported(Scheme::File))
+    );
+    assert_eq!(
+        GitUrl::builder()
+            .scheme(Scheme::File)
+            .port(22)
+            .name("repo")
+            .build(),
+        Err(GitUrlBuildError::PortWithoutHost)
+    );
+    assert_eq!(
+        GitUrl::builder().owner("owner").name("repo").build(),
+        Err(GitUrlBuildError::MissingHost(Scheme::Https))
+    );
+    assert_eq!(
+        GitUrl::builder()
+            .scheme(Scheme::Ssh)
+            .host("host.tld")
+            .owner("owner")
+            .name("repo")
+            .auth_token("secret")
+            .build(),
+        Err(GitUrlBuildError::AuthNotSupported(Scheme::Ssh))
+    );
+    assert_eq!(
+        GitUrl::builder()
+            .scheme(Scheme::Ssh)
+            .scheme_prefix(false)
+            .host("host.tld")
+            .port(2222)
+            .owner("owner")
+            .name("repo")
+            .build(),
+        Err(GitUrlBuildError::ScpLikeWithPort(2222))
+    );
+    assert_eq!(
+        GitUrl::builder()
+            .host("host.tld")
+            .subgroups("sub")
+            .owner("owner")
+            .name("repo")
+            .build(),
+        Err(GitUrlBuildError::MissingOrganization)
+    );
+    assert_eq!(
+        GitUrl::builder().host("host.tld").owner("owner").build(),
+        Err(GitUrlBuildError::MissingName)
+    );
+    assert_eq!(
+        GitUrl::builder()
+            .host("host.tld")
+            .owner("owner")
+            .name("a/b")
+            .build(),
+        Err(GitUrlBuildError::InvalidComponent(
+            "name",
+            "a/b".to_string()
+        ))
+    );
+}
+
+#[test]
+fn azure_devops_layout() {
+    let built = GitUrl::builder()
+        .host("dev.azure.com")
+        .organization("org")
+        .owner("My Project")
+        .name("repo")
+        .build()
+        .expect("Build failed");
+
+    assert_eq!(built.owner, Some("My%20Project".to_string()));
+    assert_eq!(
+        built.to_string(),
+        "https://dev.azure.com/org/My%20Project/_git/repo"
+    );
+    assert_round_trip(built);
+
+    let built = GitUrl::builder()
+        .host("org.visualstudio.com")
+        .organization("org")
+        .owner("project")
+        .name("repo")
+        .build()
+        .expect("Build failed");
+
+    assert_eq!(
+        built.to_string(),
+        "https://org.visualstudio.com/project/_git/repo"
+    );
+    assert_round_trip(built);
+
+    let built = GitUrl::builder()
+        .scheme(Scheme::Ssh)
+        .scheme_prefix(false)
+        .auth_user("git")
+        .host("ssh.dev.azure.com")
+        .organization("org")
+        .owner("project")
+        .name("repo")
+        .build()
+        .expect("Build failed");
+
+    assert_eq!(
+        built.to_string(),
+        "git@ssh.dev.azure.com:v3/org/project/repo"
+    );
+    assert_round_trip(built);
+}
+
+#[test]
+fn bitbucket_server_layout() {
+    let built = GitUrl::builder()
+        .host("bitbucket.corp.example")
+        .owner("proj")
+        .name("repo")
+        .git_suffix(true)
+        .build()
+        .expect("Build failed");
+
+    assert_eq!(
+        built.to_string(),
+        "https://bitbucket.corp.example/scm/proj/repo.git"
+    );
+    assert_round_trip(built);
+
+    let built = GitUrl::builder()
+        .scheme(Scheme::Ssh)
+        .auth_user("git")
+        .host("bitbucket.corp.example")
+        .port(7999)
+        .owner("proj")
+        .name("repo")
+        .git_suffix(true)
+        .build()
+        .expect("Build failed");
+
+    assert_eq!(
+        built.to_string(),
+        "ssh://git@bitbucket.corp.example:7999/proj/repo.git"
+    );
+    assert_round_trip(built);
+}
+
+#[test]
+fn rejects_parts_outside_provider_layout() {
+    assert_eq!(
+        GitUrl::builder()
+            .host("dev.azure.com")
+            .owner("project")
+            .name("repo")
+            .build(),
+        Err(GitUrlBuildError::UnsupportedLayout(Provider::AzureDevOps))
+    );
+    assert_eq!(
+        GitUrl::builder()
+            .host("other.visualstudio.com")
+            .organization("org")
+            .owner("project")
+            .name("repo")
+            .build(),
+        Err(GitUrlBuildError::UnsupportedLayout(Provider::AzureDevOps))
+    );
+}
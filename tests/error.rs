@@ -0,0 +1,103 @@
+use git_url_parse::*;
+
+#[test]
+fn path_where_port_should_be() {
+    let test_url = "ssh://git@github.com:org/repo.git";
+    let e = GitUrl::parse(test_url).expect_err("URL parse should fail");
+
+    assert_eq!(
+        e,
+        GitUrlParseError::UrlParseError(url::ParseError::InvalidPort)
+    );
+    assert_eq!(
+        e.with_input(test_url),
+        ErrorContext {
+            error: GitUrlParseError::NonNumericPort("org".to_string()),
+            input: test_url.to_string(),
+            span: 21..24,
+            suggestion: Some("git@github.com:org/repo.git".to_string()),
+        }
+    );
+}
+
+#[test]
+fn path_where_port_should_be_message() {
+    let test_url = "ssh://git@github.com:org/repo.git";
+    let e = GitUrl::parse(test_url).expect_err("URL parse should fail");
+
+    assert_eq!(
+        e.with_input(test_url).to_string(),
+        "port `org` is not numeric, did you mean `git@github.com:org/repo.git`?"
+    );
+}
+
+#[test]
+fn out_of_range_port() {
+    let test_url = "https://host.tld:99999/org/repo";
+    let context = GitUrl::parse(test_url)
+        .expect_err("URL parse should fail")
+        .with_input(test_url);
+
+    assert_eq!(
+        context.error,
+        GitUrlParseError::UrlParseError(url::ParseError::InvalidPort)
+    );
+    assert_eq!(context.component(), "99999");
+    assert_eq!(context.suggestion, None);
+}
+
+#[test]
+fn host_span() {
+    let test_url = "https://exa mple.com/org/repo";
+    let e = GitUrl::parse(test_url).expect_err("URL parse should fail");
+    assert_eq!(e.with_input(test_url).component(), "exa mple.com");
+
+    let test_url = "git@:org/repo";
+    let e = GitUrl::parse(test_url).expect_err("URL parse should fail");
+    assert_eq!(e.with_input(test_url).span, 4..4);
+}
+
+#[test]
+fn path_span() {
+    let test_url = "git@test.com:repo";
+    let context = GitUrl::parse(test_url)
+        .expect_err("URL parse should fail")
+        .with_input(test_url);
+
+    assert_eq!(context.error, GitUrlParseError::UnexpectedFormat);
+    assert_eq!(context.component(), "repo");
+    assert_eq!(context.to_string(), "Git Url not in expected format");
+}
+
+#[test]
+fn null_byte_span() {
+    let test_url = "https://host.tld/org/re\0po";
+    let context = GitUrl::parse(test_url)
+        .expect_err("URL parse should fail")
+        .with_input(test_url);
+
+    assert_eq!(context.error, GitUrlParseError::FoundNullBytes);
+    assert_eq!(context.span, 23..24);
+}
+
+#[test]
+fn other_input_spans_whole_input() {
+    let test_url = "https://host.tld/org/re\0po";
+    let e = GitUrl::parse(test_url).expect_err("URL parse should fail");
+    let context = e.with_input("é");
+
+    assert_eq!(context.span, 0..2);
+    assert_eq!(context.component(), "é");
+
+    for pos in [1, 2, 100] {
+        let context = GitUrlParseError::InvalidUriCharacter(pos).with_input("é");
+        assert_eq!(context.span, 0..2);
+    }
+}
+
+#[test]
+fn errors_can_be_matched() {
+    let e = GitUrl::parse("git@test.com:repo").expect_err("URL parse should fail");
+
+    assert!(matches!(e, GitUrlParseError::UnexpectedFormat));
+}
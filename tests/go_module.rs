@@ -0,0 +1,118 @@
+use git_url_parse::*;
+
+#[test]
+fn github_major_version() {
+    let parsed = GoModule::resolve("github.com/org/repo/v2/pkg").expect("Resolve failed");
+    let expected = GoModule {
+        root: "github.com/org/repo".to_string(),
+        repo: GitUrl {
+            host: Some("github.com".to_string()),
+            name: "repo".to_string(),
+            owner: Some("org".to_string()),
+            subgroups: None,
+            organization: None,
+            fullname: "org/repo".to_string(),
+            helper: None,
+            scheme: Scheme::Https,
+            auth_user: None,
+            auth_token: None,
+            port: None,
+            path: "/org/repo".to_string(),
+            path_base: PathBase::Default,
+            git_suffix: false,
+            scheme_prefix: true,
+            provider: Provider::GitHub,
+            extra: None,
+            source: None,
+        },
+        vcs: Vcs::Git,
+        subdir: Some("pkg".to_string()),
+        major: Some(2),
+    };
+
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn vcs_suffix() {
+    let parsed = GoModule::resolve("gitlab.com/g/sub/proj.git/inner").expect("Resolve failed");
+
+    assert_eq!(parsed.root, "gitlab.com/g/sub/proj.git");
+    assert_eq!(parsed.vcs, Vcs::Git);
+    assert_eq!(parsed.repo.to_string(), "https://gitlab.com/g/sub/proj.git");
+    assert_eq!(parsed.repo.organization, Some("g".to_string()));
+    assert_eq!(parsed.repo.owner, Some("sub".to_string()));
+    assert_eq!(parsed.repo.name, "proj");
+    assert_eq!(parsed.subdir, Some("inner".to_string()));
+    assert_eq!(parsed.major, None);
+
+    let hg = GoModule::resolve("hg.example.com/repo.hg").expect("Resolve failed");
+    assert_eq!(hg.vcs, Vcs::Mercurial);
+    assert_eq!(hg.subdir, None);
+}
+
+#[test]
+fn gopkg_in() {
+    let yaml = GoModule::resolve("gopkg.in/yaml.v3").expect("Resolve failed");
+    assert_eq!(yaml.repo.to_string(), "https://github.com/go-yaml/yaml");
+    assert_eq!(yaml.major, Some(3));
+
+    let user = GoModule::resolve("gopkg.in/user/pkg.v1/sub").expect("Resolve failed");
+    assert_eq!(user.root, "gopkg.in/user/pkg.v1");
+    assert_eq!(user.repo.fullname, "user/pkg");
+    assert_eq!(user.subdir, Some("sub".to_string()));
+}
+
+#[test]
+fn vanity_path() {
+    let html = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta http-equiv="Content-Type" content="text/html; charset=utf-8"/>
+<meta name="go-import" content="golang.org/x/net mod https://proxy.golang.org">
+<meta name='go-import' content='golang.org/x/net git https://go.googlesource.com/net'>
+<meta name="go-source" content="golang.org/x/net https://github.com/golang/net/ https://github.com/golang/net/tree/master{/dir}">
+</head>
+<body>
+<meta name="go-import" content="golang.org/x/net git https://example.com/ignored/net">
+</body>
+</html>"#;
+    let parsed =
+        GoModule::resolve_with_html("golang.org/x/net/html/atom", html).expect("Resolve failed");
+
+    assert_eq!(parsed.root, "golang.org/x/net");
+    assert_eq!(parsed.vcs, Vcs::Git);
+    assert_eq!(parsed.repo.host, Some("go.googlesource.com".to_string()));
+    assert_eq!(parsed.repo.name, "net");
+    assert_eq!(parsed.subdir, Some("html/atom".to_string()));
+}
+
+#[test]
+fn vanity_path_with_repo_subdir() {
+    let html = r#"<meta name="go-import" content="example.com/mod git https://git.example.com/org/mono.git go/mod" />"#;
+    let parsed =
+        GoModule::resolve_with_html("example.com/mod/v3/pkg", html).expect("Resolve failed");
+
+    assert_eq!(parsed.repo.fullname, "org/mono");
+    assert_eq!(parsed.subdir, Some("go/mod/pkg".to_string()));
+    assert_eq!(parsed.major, Some(3));
+}
+
+#[test]
+fn unresolved_paths() {
+    assert_eq!(
+        GoModule::resolve("golang.org/x/net"),
+        Err(GitUrlParseError::MissingGoImport(
+            "golang.org/x/net".to_string()
+        ))
+    );
+    // The prefix must end at a path element
+    let html =
+        r#"<meta name="go-import" content="example.com/mod git https://git.example.com/org/mod">"#;
+    assert_eq!(
+        GoModule::resolve_with_html("example.com/module", html),
+        Err(GitUrlParseError::MissingGoImport(
+            "example.com/module".to_string()
+        ))
+    );
+}
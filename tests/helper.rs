@@ -0,0 +1,93 @@
+use git_url_parse::*;
+
+#[test]
+fn parsed_address() {
+    let test_url = "persistent-https::https://host.example.com/org/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("host.example.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("org".to_string()),
+        subgroups: None,
+        organization: None,
+        fullname: "org/repo".to_string(),
+        helper: Some("persistent-https".to_string()),
+        scheme: Scheme::Https,
+        auth_user: None,
+        auth_token: None,
+        port: None,
+        path: "/org/repo.git".to_string(),
+        path_base: PathBase::Default,
+        git_suffix: true,
+        scheme_prefix: true,
+        provider: Provider::Generic,
+        extra: None,
+        source: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn scp_like_address() {
+    let test_url = "gcrypt::git@github.com:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.helper, Some("gcrypt".to_string()));
+    assert_eq!(parsed.scheme, Scheme::Ssh);
+    assert_eq!(parsed.provider, Provider::GitHub);
+    assert_eq!(parsed.fullname, "owner/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn other_scheme_address() {
+    let test_url = "s3::s3://bucket/path/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.helper, Some("s3".to_string()));
+    assert_eq!(parsed.scheme, Scheme::Other("s3".to_string()));
+    assert_eq!(parsed.host, Some("bucket".to_string()));
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn opaque_address() {
+    for (test_url, name) in [
+        ("codecommit::us-east-1://my-repo", "my-repo"),
+        ("ext::ssh -i key host %S 'repo.git'", "repo"),
+    ] {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+        assert!(parsed.helper.is_some());
+        assert_eq!(parsed.name, name);
+        assert_eq!(parsed.host, None);
+        assert_eq!(parsed.to_string(), test_url);
+    }
+
+    // Addresses that parse are not opaque, even for helpers of other vcs
+    let parsed = GitUrl::parse("hg::https://hg.example.com/repo").expect("URL parse failed");
+    assert_eq!(parsed.helper, Some("hg".to_string()));
+    assert_eq!(parsed.host, Some("hg.example.com".to_string()));
+}
+
+#[test]
+fn other_scheme() {
+    let test_url = "custom+proto://host.example.com/org/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.helper, None);
+    assert_eq!(parsed.scheme, Scheme::Other("custom+proto".to_string()));
+    assert_eq!(parsed.fullname, "org/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn scp_like_is_not_a_scheme() {
+    let parsed = GitUrl::parse("host.tld:user/project-name.git").expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::Ssh);
+    assert_eq!(parsed.host, Some("host.tld".to_string()));
+}
@@ -0,0 +1,121 @@
+use git_url_parse::*;
+
+#[test]
+fn ssh_other_user_home() {
+    let test_url = "ssh://host.tld/~user/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.path_base, PathBase::Home(Some("user".to_string())));
+    assert_eq!(parsed.path, "~user/repo.git");
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn scp_like_own_home() {
+    let test_url = "git@host.tld:~/org/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.path_base, PathBase::Home(None));
+    assert_eq!(parsed.owner, Some("org".to_string()));
+    assert_eq!(parsed.fullname, "org/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn git_other_user_home() {
+    let test_url = "git://host.tld/~user/org/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.path_base, PathBase::Home(Some("user".to_string())));
+    assert_eq!(parsed.path, "/~user/org/repo");
+    assert_eq!(parsed.owner, Some("org".to_string()));
+    assert_eq!(parsed.organization, None);
+}
+
+#[test]
+fn bitbucket_server_personal_repo() {
+    let test_url = "ssh://git@bitbucket.example.com:7999/~user/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.path_base, PathBase::Default);
+    assert_eq!(parsed.owner, Some("~user".to_string()));
+}
+
+#[test]
+fn ssh_home_repo_has_no_owner() {
+    let test_url = "ssh://git@host.com/~user/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.path_base, PathBase::Home(Some("user".to_string())));
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "repo");
+}
+
+#[test]
+fn sourcehut_owner() {
+    let ssh = GitUrl::parse("git@git.sr.ht:~sircmpwn/scdoc").expect("URL parse failed");
+    let https = GitUrl::parse("https://git.sr.ht/~sircmpwn/scdoc").expect("URL parse failed");
+
+    assert_eq!(ssh.path_base, PathBase::Default);
+    assert_eq!(ssh.owner, Some("~sircmpwn".to_string()));
+    assert_eq!(ssh.fullname, "~sircmpwn/scdoc");
+    assert!(ssh.same_repo(&https));
+    assert_eq!(
+        ssh.to_https().expect("to_https failed").to_string(),
+        "https://git.sr.ht/~sircmpwn/scdoc"
+    );
+    assert_eq!(
+        https.to_ssh().expect("to_ssh failed").to_string(),
+        "ssh://git@git.sr.ht/~sircmpwn/scdoc"
+    );
+}
+
+#[test]
+fn home_repos_are_distinct() {
+    let mine = GitUrl::parse("git@host.tld:~/org/repo.git").expect("URL parse failed");
+    let other = GitUrl::parse("git@host.tld:org/repo.git").expect("URL parse failed");
+
+    assert!(!mine.same_repo(&other));
+}
+
+#[test]
+fn local_path_expansion() {
+    let test_url = "~/path/to/repo.git/";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::File);
+    assert_eq!(parsed.path_base, PathBase::Home(None));
+    assert_eq!(parsed.path, "~/path/to/repo.git");
+
+    let expanded = parsed.expand_home("/home/user/").expect("URL parse failed");
+    assert_eq!(expanded.path_base, PathBase::Default);
+    assert_eq!(expanded.path, "/home/user/path/to/repo.git");
+    assert_eq!(expanded.name, "repo");
+
+    // Remote urls are not expanded
+    let remote = GitUrl::parse("git@host.tld:~/repo.git").expect("URL parse failed");
+    assert_eq!(remote.expand_home("/home/user"), Ok(remote.clone()));
+}
+
+#[test]
+fn builder_home() {
+    let url = GitUrl::builder()
+        .scheme(Scheme::Ssh)
+        .auth_user("git")
+        .host("host.tld")
+        .path_base(PathBase::Home(Some("user".to_string())))
+        .owner("org")
+        .name("repo")
+        .build()
+        .expect("Invalid url parts");
+
+    assert_eq!(url.to_string(), "ssh://git@host.tld/~user/org/repo");
+    assert_eq!(
+        url,
+        GitUrl::parse(&url.to_string()).expect("URL parse failed")
+    );
+}
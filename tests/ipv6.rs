@@ -0,0 +1,123 @@
+use git_url_parse::*;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[test]
+fn scp_like() {
+    let test_url = "git@[2001:db8::1]:org/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("2001:db8::1".to_string()),
+        name: "repo".to_string(),
+        owner: Some("org".to_string()),
+        subgroups: None,
+        organization: None,
+        fullname: "org/repo".to_string(),
+        helper: None,
+        scheme: Scheme::Ssh,
+        auth_user: Some("git".to_string()),
+        auth_token: None,
+        port: None,
+        path: "org/repo.git".to_string(),
+        path_base: PathBase::Default,
+        git_suffix: true,
+        scheme_prefix: false,
+        provider: Provider::Generic,
+        extra: None,
+        source: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn scp_like_without_user() {
+    let test_url = "[::1]:org/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::Ssh);
+    assert_eq!(parsed.host, Some("::1".to_string()));
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn ssh_with_port() {
+    let test_url = "ssh://[::1]:2222/org/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("::1".to_string()));
+    assert_eq!(parsed.port, Some(2222));
+    assert_eq!(parsed.fullname, "org/repo");
+    assert_eq!(parsed.to_string(), test_url);
+}
+
+#[test]
+fn zone_id() {
+    let test_url = "ssh://git@[fe80::1%eth0]:2222/org/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(parsed.host, Some("fe80::1%eth0".to_string()));
+    assert_eq!(
+        parsed.typed_host(),
+        Some(Host::Ipv6(
+            "fe80::1".parse::<Ipv6Addr>().unwrap(),
+            Some("eth0".to_string())
+        ))
+    );
+    assert_eq!(parsed.to_string(), test_url);
+
+    // The url-encoded separator is accepted too
+    let encoded = GitUrl::parse("git@[fe80::1%25eth0]:org/repo.git").expect("URL parse failed");
+    assert_eq!(encoded.host, parsed.host);
+}
+
+#[test]
+fn git_and_https() {
+    let parsed = GitUrl::parse("git://[2001:db8::1]/org/repo").expect("URL parse failed");
+    assert_eq!(parsed.host, Some("2001:db8::1".to_string()));
+    assert_eq!(parsed.to_string(), "git://[2001:db8::1]/org/repo");
+
+    let parsed =
+        GitUrl::parse("https://[2001:db8::1]:8443/org/repo.git").expect("URL parse failed");
+    assert_eq!(parsed.port, Some(8443));
+    assert_eq!(
+        parsed.to_string(),
+        "https://[2001:db8::1]:8443/org/repo.git"
+    );
+}
+
+#[test]
+fn typed_host() {
+    let parsed = GitUrl::parse("https://github.com/org/repo").expect("URL parse failed");
+    assert_eq!(
+        parsed.typed_host(),
+        Some(Host::Domain("github.com".to_string()))
+    );
+
+    let parsed = GitUrl::parse("http://192.0.2.1/org/repo").expect("URL parse failed");
+    assert_eq!(
+        parsed.typed_host(),
+        Some(Host::Ipv4(Ipv4Addr::new(192, 0, 2, 1)))
+    );
+    assert_eq!(
+        "[2001:db8::1]".parse::<Host>().unwrap().to_string(),
+        "[2001:db8::1]"
+    );
+    assert!("[fe80::1%]".parse::<Host>().is_err());
+}
+
+#[test]
+fn builder_brackets() {
+    let url = GitUrl::builder()
+        .scheme(Scheme::Ssh)
+        .scheme_prefix(false)
+        .auth_user("git")
+        .host("[2001:db8::1]")
+        .owner("org")
+        .name("repo")
+        .build()
+        .expect("Invalid url parts");
+
+    assert_eq!(url.host, Some("2001:db8::1".to_string()));
+    assert_eq!(url.to_string(), "git@[2001:db8::1]:org/repo");
+}
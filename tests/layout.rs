@@ -0,0 +1,133 @@
+use git_url_parse::*;
+
+/// Treats everything after a `-` segment as extra path, like GitLab web urls
+#[derive(Debug)]
+struct DashSeparatedLayout;
+
+impl HostLayout for DashSeparatedLayout {
+    fn split(&self, segments: &[&str]) -> Result<RepoPath, GitUrlParseError> {
+        let end = segments
+            .iter()
+            .position(|s| *s == "-")
+            .unwrap_or(segments.len());
+        let mut repo_path = DefaultLayout.split(&segments[..end])?;
+        if end < segments.len() {
+            repo_path.extra = Some(segments[end + 1..].join("/"));
+        }
+        Ok(repo_path)
+    }
+}
+
+#[test]
+fn default_layout_subgroups() {
+    let repo_path = DefaultLayout
+        .split(&["group", "sub1", "sub2", "owner", "repo.git"])
+        .expect("Layout split failed");
+    let expected = RepoPath {
+        owner: Some("owner".to_string()),
+        subgroups: Some("sub1/sub2".to_string()),
+        organization: Some("group".to_string()),
+        name: "repo".to_string(),
+        extra: None,
+    };
+
+    assert_eq!(repo_path, expected);
+    assert_eq!(repo_path.fullname(), "group/sub1/sub2/owner/repo");
+}
+
+#[test]
+fn default_layout_no_segments() {
+    assert_eq!(
+        DefaultLayout.split(&[]),
+        Err(GitUrlParseError::UnexpectedFormat)
+    );
+}
+
+#[test]
+fn prefix_layout_relative_root() {
+    let mut registry = HostRegistry::new();
+    registry.register_layout("git.corp.example", PrefixLayout::new("/gitlab/"));
+
+    let parsed =
+        GitUrl::parse_with_registry("https://git.corp.example/gitlab/group/repo.git", &registry)
+            .expect("URL parse failed");
+
+    assert_eq!(parsed.owner, Some("group".to_string()));
+    assert_eq!(parsed.organization, None);
+    assert_eq!(parsed.fullname, "group/repo");
+    assert_eq!(parsed.path, "/gitlab/group/repo.git");
+}
+
+#[test]
+fn bitbucket_server_scm_prefix() {
+    let parsed = GitUrl::parse("https://bitbucket.corp.example/scm/proj/repo.git")
+        .expect("URL parse failed");
+
+    assert_eq!(parsed.provider, Provider::BitbucketServer);
+    assert_eq!(parsed.owner, Some("proj".to_string()));
+    assert_eq!(parsed.organization, None);
+    assert_eq!(parsed.fullname, "proj/repo");
+}
+
+#[test]
+fn custom_layout_extra_path() {
+    let mut registry = HostRegistry::new();
+    registry.register_layout("gitlab.example.com", DashSeparatedLayout);
+
+    let parsed = GitUrl::parse_with_registry(
+        "https://gitlab.example.com/group/sub/repo/-/tree/main",
+        &registry,
+    )
+    .expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.owner, Some("sub".to_string()));
+    assert_eq!(parsed.organization, Some("group".to_string()));
+    assert_eq!(parsed.fullname, "group/sub/repo");
+    assert_eq!(parsed.extra, Some("tree/main".to_string()));
+}
+
+#[test]
+fn default_layout_extra_path() {
+    let parsed =
+        GitUrl::parse("https://host.tld/org/repo.git/info/refs").expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.owner, Some("org".to_string()));
+    assert_eq!(parsed.fullname, "org/repo");
+    assert_eq!(parsed.extra, Some("info/refs".to_string()));
+
+    let parsed = GitUrl::parse("https://host.tld/org/repo.git").expect("URL parse failed");
+    assert_eq!(parsed.extra, None);
+}
+
+#[test]
+fn azure_devops_extra_path() {
+    let parsed =
+        GitUrl::parse("https://dev.azure.com/org/proj/_git/repo/extra").expect("URL parse failed");
+
+    assert_eq!(parsed.name, "repo");
+    assert_eq!(parsed.fullname, "org/proj/repo");
+    assert_eq!(parsed.extra, Some("extra".to_string()));
+}
+
+#[test]
+fn layout_error_is_returned() {
+    #[derive(Debug)]
+    struct TwoSegmentLayout;
+
+    impl HostLayout for TwoSegmentLayout {
+        fn split(&self, segments: &[&str]) -> Result<RepoPath, GitUrlParseError> {
+            match segments {
+                [_, _] => DefaultLayout.split(segments),
+                _ => Err(GitUrlParseError::UnexpectedFormat),
+            }
+        }
+    }
+
+    let mut registry = HostRegistry::new();
+    registry.register_layout("host.tld", TwoSegmentLayout);
+
+    let e = GitUrl::parse_with_registry("https://host.tld/org/owner/repo.git", &registry);
+    assert_eq!(e, Err(GitUrlParseError::UnexpectedFormat));
+}
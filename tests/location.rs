@@ -0,0 +1,204 @@
+use git_url_parse::*;
+
+#[test]
+fn github_tree() {
+    let test_url = "https://github.com/owner/repo/tree/main/src";
+    let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(location.repo.owner, Some("owner".to_string()));
+    assert_eq!(location.repo.name, "repo");
+    assert_eq!(location.repo.fullname, "owner/repo");
+    assert_eq!(location.repo.to_string(), "https://github.com/owner/repo");
+    assert_eq!(location.kind, Some(LocationKind::Tree));
+    assert_eq!(location.git_ref, Some("main".to_string()));
+    assert_eq!(location.path, Some("src".to_string()));
+    assert_eq!(location.lines, None);
+}
+
+#[test]
+fn github_blob_with_lines() {
+    let test_url = "https://github.com/owner/repo/blob/v1.2/README.md#L10-L20";
+    let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(location.repo.fullname, "owner/repo");
+    assert_eq!(location.kind, Some(LocationKind::Blob));
+    assert_eq!(location.git_ref, Some("v1.2".to_string()));
+    assert_eq!(location.path, Some("README.md".to_string()));
+    assert_eq!(
+        location.lines,
+        Some(LineRange {
+            start: 10,
+            end: Some(20)
+        })
+    );
+}
+
+#[test]
+fn github_commit() {
+    let test_url = "https://github.com/owner/repo/commit/0123abc";
+    let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(location.kind, Some(LocationKind::Commit));
+    assert_eq!(location.git_ref, Some("0123abc".to_string()));
+    assert_eq!(location.path, None);
+}
+
+#[test]
+fn raw_githubusercontent() {
+    let test_url = "https://raw.githubusercontent.com/owner/repo/main/docs/a%20b.md";
+    let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(location.repo.host, Some("github.com".to_string()));
+    assert_eq!(location.repo.provider, Provider::GitHub);
+    assert_eq!(location.repo.fullname, "owner/repo");
+    assert_eq!(location.kind, Some(LocationKind::Raw));
+    assert_eq!(location.git_ref, Some("main".to_string()));
+    assert_eq!(location.path, Some("docs/a b.md".to_string()));
+}
+
+#[test]
+fn gitlab_subgroups_blob() {
+    let test_url = "https://gitlab.com/group/sub/repo/-/blob/main/x.rs#L3";
+    let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(location.repo.organization, Some("group".to_string()));
+    assert_eq!(location.repo.owner, Some("sub".to_string()));
+    assert_eq!(location.repo.name, "repo");
+    assert_eq!(location.repo.fullname, "group/sub/repo");
+    assert_eq!(location.kind, Some(LocationKind::Blob));
+    assert_eq!(location.git_ref, Some("main".to_string()));
+    assert_eq!(location.path, Some("x.rs".to_string()));
+    assert_eq!(
+        location.lines,
+        Some(LineRange {
+            start: 3,
+            end: None
+        })
+    );
+}
+
+#[test]
+fn bitbucket_cloud_src() {
+    let test_url = "https://bitbucket.org/owner/repo/src/4f2e1c0/path/to/file.py#lines-5:9";
+    let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(location.repo.fullname, "owner/repo");
+    assert_eq!(location.kind, Some(LocationKind::Blob));
+    assert_eq!(location.git_ref, Some("4f2e1c0".to_string()));
+    assert_eq!(location.path, Some("path/to/file.py".to_string()));
+    assert_eq!(
+        location.lines,
+        Some(LineRange {
+            start: 5,
+            end: Some(9)
+        })
+    );
+}
+
+#[test]
+fn bitbucket_server_browse() {
+    let test_url =
+        "https://bitbucket.corp.example/projects/PROJ/repos/repo/browse/src/main.c?at=refs%2Fheads%2Fdevelop#12-14";
+    let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(location.repo.provider, Provider::BitbucketServer);
+    assert_eq!(location.repo.path, "/scm/PROJ/repo");
+    assert_eq!(location.repo.fullname, "PROJ/repo");
+    assert_eq!(location.kind, Some(LocationKind::Blob));
+    assert_eq!(location.git_ref, Some("develop".to_string()));
+    assert_eq!(location.path, Some("src/main.c".to_string()));
+    assert_eq!(
+        location.lines,
+        Some(LineRange {
+            start: 12,
+            end: Some(14)
+        })
+    );
+}
+
+#[test]
+fn gitea_src_branch() {
+    let test_url = "https://codeberg.org/owner/repo/src/branch/main/lib";
+    let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(location.repo.fullname, "owner/repo");
+    assert_eq!(location.kind, Some(LocationKind::Blob));
+    assert_eq!(location.git_ref, Some("main".to_string()));
+    assert_eq!(location.path, Some("lib".to_string()));
+}
+
+#[test]
+fn azure_devops_query() {
+    let test_url = "https://dev.azure.com/org/project/_git/repo?path=/src/app.ts&version=GBmain&line=4&lineEnd=8";
+    let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+    assert_eq!(location.repo.fullname, "org/project/repo");
+    assert_eq!(location.kind, Some(LocationKind::Blob));
+    assert_eq!(location.git_ref, Some("main".to_string()));
+    assert_eq!(location.path, Some("src/app.ts".to_string()));
+    assert_eq!(
+        location.lines,
+        Some(LineRange {
+            start: 4,
+            end: Some(8)
+        })
+    );
+}
+
+#[test]
+fn clone_urls_have_no_location() {
+    for test_url in [
+        "https://github.com/owner/repo.git",
+        "git@github.com:owner/repo.git",
+    ] {
+        let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+        assert_eq!(
+            location.repo,
+            GitUrl::parse(test_url).expect("URL parse failed")
+        );
+        assert_eq!(location.kind, None);
+        assert_eq!(location.git_ref, None);
+        assert_eq!(location.path, None);
+        assert_eq!(location.lines, None);
+    }
+}
+
+#[test]
+fn repo_pages_name_the_repo() {
+    for test_url in [
+        "https://github.com/owner/repo/pull/1",
+        "https://github.com/owner/repo/issues/3",
+        "https://github.com/owner/repo/releases/tag/v1",
+        "https://codeberg.org/owner/repo/pulls/2",
+        "https://bitbucket.org/owner/repo/pull-requests/5",
+    ] {
+        let location = RepoLocation::parse(test_url).expect("URL parse failed");
+
+        assert_eq!(location.repo.owner, Some("owner".to_string()));
+        assert_eq!(location.repo.name, "repo");
+        assert_eq!(location.repo.fullname, "owner/repo");
+        assert_eq!(location.kind, None);
+        assert_eq!(location.git_ref, None);
+        assert_eq!(location.path, None);
+    }
+}
+
+#[test]
+fn line_range_fragments() {
+    assert_eq!(
+        LineRange::from_fragment("L10C2-L12C8"),
+        Some(LineRange {
+            start: 10,
+            end: Some(12)
+        })
+    );
+    assert_eq!(
+        LineRange::from_fragment("L7-L7"),
+        Some(LineRange {
+            start: 7,
+            end: None
+        })
+    );
+    assert_eq!(LineRange::from_fragment("readme"), None);
+}
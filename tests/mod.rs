@@ -1,3 +1,27 @@
+mod azure_devops;
+mod borrowed;
+mod builder;
+mod convert;
+mod error;
+mod go_module;
+mod helper;
+mod home;
+mod identity;
+mod ipv6;
+mod layout;
+mod location;
 mod normalize;
+mod npm;
+mod options;
 mod parse;
+mod provider;
+mod rewrite;
+mod scheme;
+mod serde;
+mod source;
+mod ssh_config;
+mod submodule;
+mod terraform;
 mod trim_auth;
+mod validation;
+mod web;
@@ -0,0 +1,137 @@
+use git_url_parse::*;
+
+#[test]
+fn github_shortcut_semver() {
+    let test_spec = "github:user/repo#semver:^1.0";
+    let parsed = NpmSpec::parse(test_spec).expect("Spec parse failed");
+    let expected = NpmSpec {
+        repo: GitUrl {
+            host: Some("github.com".to_string()),
+            name: "repo".to_string(),
+            owner: Some("user".to_string()),
+            subgroups: None,
+            organization: None,
+            fullname: "user/repo".to_string(),
+            helper: None,
+            scheme: Scheme::Https,
+            auth_user: None,
+            auth_token: None,
+            port: None,
+            path: "/user/repo.git".to_string(),
+            path_base: PathBase::Default,
+            git_suffix: true,
+            scheme_prefix: true,
+            provider: Provider::GitHub,
+            extra: None,
+            source: None,
+        },
+        committish: None,
+        semver: Some("^1.0".to_string()),
+        path: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(
+        parsed.to_string(),
+        "https://github.com/user/repo.git#semver:^1.0"
+    );
+}
+
+#[test]
+fn gitlab_and_bitbucket_shortcuts() {
+    let gitlab = NpmSpec::parse("gitlab:group/sub/repo").expect("Spec parse failed");
+    assert_eq!(
+        gitlab.repo.to_string(),
+        "https://gitlab.com/group/sub/repo.git"
+    );
+    assert_eq!(gitlab.repo.provider, Provider::GitLab);
+    assert_eq!(gitlab.repo.fullname, "group/sub/repo");
+
+    let bitbucket = NpmSpec::parse("bitbucket:team/repo#v1.2.0").expect("Spec parse failed");
+    assert_eq!(
+        bitbucket.repo.to_string(),
+        "https://bitbucket.org/team/repo.git"
+    );
+    assert_eq!(bitbucket.repo.provider, Provider::BitbucketCloud);
+    assert_eq!(bitbucket.committish, Some("v1.2.0".to_string()));
+}
+
+#[test]
+fn gist_shortcut() {
+    let parsed = NpmSpec::parse("gist:user/abc123#main").expect("Spec parse failed");
+
+    assert_eq!(
+        parsed.repo.to_string(),
+        "https://gist.github.com/abc123.git"
+    );
+    assert_eq!(parsed.repo.owner, None);
+    assert_eq!(parsed.repo.name, "abc123");
+    assert_eq!(parsed.repo.provider, Provider::GitHub);
+    assert_eq!(parsed.committish, Some("main".to_string()));
+    assert_eq!(
+        NpmSpec::parse("gist:abc123")
+            .expect("Spec parse failed")
+            .repo,
+        parsed.repo
+    );
+}
+
+#[test]
+fn bare_github_shorthand() {
+    let parsed: NpmSpec = "user/repo#feature/branch"
+        .parse()
+        .expect("Spec parse failed");
+
+    assert_eq!(parsed.repo.to_string(), "https://github.com/user/repo.git");
+    assert_eq!(parsed.committish, Some("feature/branch".to_string()));
+}
+
+#[test]
+fn scp_like_after_scheme() {
+    let parsed = NpmSpec::parse("git+ssh://git@github.com:user/repo.git#semver:~1.2::path:/pkg")
+        .expect("Spec parse failed");
+
+    assert_eq!(parsed.repo.scheme, Scheme::GitSsh);
+    assert_eq!(parsed.repo.host, Some("github.com".to_string()));
+    assert_eq!(parsed.repo.fullname, "user/repo");
+    assert_eq!(parsed.semver, Some("~1.2".to_string()));
+    assert_eq!(parsed.path, Some("pkg".to_string()));
+
+    let single =
+        NpmSpec::parse("git+ssh://git@host.tld:repo.git#0123abc").expect("Spec parse failed");
+    assert_eq!(single.repo.name, "repo");
+    assert_eq!(single.repo.path, "/repo.git");
+    assert_eq!(single.committish, Some("0123abc".to_string()));
+
+    // Numeric ports are kept
+    let port =
+        NpmSpec::parse("git+ssh://git@host.tld:2222/org/repo.git").expect("Spec parse failed");
+    assert_eq!(port.repo.port, Some(2222));
+}
+
+#[test]
+fn git_urls() {
+    let parsed = NpmSpec::parse("git+https://github.com/user/repo.git").expect("Spec parse failed");
+
+    assert_eq!(parsed.repo.scheme, Scheme::GitHttps);
+    assert_eq!(parsed.committish, None);
+    assert_eq!(parsed.to_string(), "git+https://github.com/user/repo.git");
+}
+
+#[test]
+fn non_git_specs() {
+    for spec in ["^1.2.3", "latest", "./local", "@scope/pkg", "github:"] {
+        assert!(NpmSpec::parse(spec).is_err(), "{}", spec);
+    }
+}
+
+#[test]
+fn scp_like() {
+    let parsed = NpmSpec::parse("git@github.com:user/repo.git#v1.0.0").expect("Spec parse failed");
+
+    assert_eq!(parsed.repo.scheme, Scheme::Ssh);
+    assert_eq!(parsed.repo.host, Some("github.com".to_string()));
+    assert_eq!(parsed.repo.fullname, "user/repo");
+    assert_eq!(parsed.committish, Some("v1.0.0".to_string()));
+    assert_eq!(parsed.to_string(), "git@github.com:user/repo.git#v1.0.0");
+}
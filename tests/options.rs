@@ -0,0 +1,107 @@
+use git_url_parse::*;
+
+#[test]
+fn default_options_match_parse() {
+    for test_url in [
+        "https://github.com/owner/repo.git",
+        "git@github.com:owner/repo.git",
+        "git:host.tld/user/project-name.git",
+        "../repo.git",
+        "persistent-https::https://host.tld/org/repo.git",
+    ] {
+        assert_eq!(
+            GitUrl::parse_with(test_url, &ParseOptions::default()),
+            GitUrl::parse(test_url)
+        );
+    }
+}
+
+#[test]
+fn strict() {
+    let options = ParseOptions::new().strict(true);
+    assert!(GitUrl::parse_with("https://github.com/owner/repo.git", &options).is_ok());
+    assert!(GitUrl::parse_with("ssh://git@host.tld:2222/org/re%20po.git", &options).is_ok());
+
+    for (test_url, style) in [
+        ("git@github.com:owner/repo.git", UrlStyle::ScpLike),
+        ("git:host.tld/user/project-name.git", UrlStyle::ShortGit),
+        ("../repo.git", UrlStyle::LocalPath),
+    ] {
+        let e = GitUrl::parse_with(test_url, &options).expect_err("URL parse should fail");
+        assert_eq!(e, GitUrlParseError::UnsupportedStyle(style));
+    }
+
+    let test_url = "https://host.tld/org/my repo.git";
+    let e = GitUrl::parse_with(test_url, &options).expect_err("URL parse should fail");
+    assert_eq!(e, GitUrlParseError::InvalidUriCharacter(23));
+    assert_eq!(e.with_input(test_url).component(), " ");
+
+    let e = GitUrl::parse_with("https://host.tld/org/100%.git", &options)
+        .expect_err("URL parse should fail");
+    assert_eq!(e, GitUrlParseError::InvalidUriCharacter(24));
+}
+
+#[test]
+fn git_shorthand_and_local_paths() {
+    let options = ParseOptions::new().git_shorthand(false).local_paths(false);
+    assert!(GitUrl::parse_with("git@github.com:owner/repo.git", &options).is_ok());
+    assert!(GitUrl::parse_with("file:///srv/git/repo.git", &options).is_ok());
+
+    for (test_url, style) in [
+        ("git:host.tld/user/project-name.git", UrlStyle::ShortGit),
+        ("~/repo.git", UrlStyle::LocalPath),
+        ("..\\repo.git", UrlStyle::WindowsPath),
+    ] {
+        let e = GitUrl::parse_with(test_url, &options).expect_err("URL parse should fail");
+        assert_eq!(e, GitUrlParseError::UnsupportedStyle(style));
+    }
+}
+
+#[test]
+fn default_host() {
+    let options = ParseOptions::new().default_host("gitlab.com");
+    let parsed = GitUrl::parse_with("owner/repo", &options).expect("URL parse failed");
+    assert_eq!(
+        parsed,
+        GitUrl::parse("https://gitlab.com/owner/repo").unwrap()
+    );
+    assert_eq!(parsed.provider, Provider::GitLab);
+
+    // Only a bare `owner/repo` is taken for a remote repo
+    for test_url in ["./repo", "~/repo", "a/b/c"] {
+        let parsed = GitUrl::parse_with(test_url, &options).expect("URL parse failed");
+        assert_eq!(parsed.scheme, Scheme::File, "{}", test_url);
+    }
+
+    // Errors are found in the input, not the url it is expanded to
+    let test_url = "owner/re\0po";
+    let e = GitUrl::parse_with(test_url, &options).expect_err("URL parse should fail");
+    assert_eq!(e, GitUrlParseError::FoundNullBytes);
+    assert_eq!(e.with_input(test_url).span, 8..9);
+}
+
+#[test]
+fn allowed_schemes() {
+    let options = ParseOptions::new().allowed_schemes(&[Scheme::Https, Scheme::Ssh]);
+    assert!(GitUrl::parse_with("https://github.com/owner/repo.git", &options).is_ok());
+    assert!(GitUrl::parse_with("git@github.com:owner/repo.git", &options).is_ok());
+
+    let test_url = "http://github.com/owner/repo.git";
+    let e = GitUrl::parse_with(test_url, &options).expect_err("URL parse should fail");
+    assert_eq!(e, GitUrlParseError::UnsupportedScheme("http".to_string()));
+    assert_eq!(e.with_input(test_url).component(), "http");
+
+    let e = GitUrl::parse_with("../repo.git", &options).expect_err("URL parse should fail");
+    assert_eq!(e, GitUrlParseError::UnsupportedScheme("file".to_string()));
+}
+
+#[test]
+fn max_length() {
+    let options = ParseOptions::new().max_length(32);
+    assert!(GitUrl::parse_with("https://host.tld/org/repo.git", &options).is_ok());
+
+    let e = GitUrl::parse_with("https://host.tld/organization/repo.git", &options)
+        .expect_err("URL parse should fail");
+    assert_eq!(e, GitUrlParseError::InputTooLong(32));
+    assert_eq!(e.to_string(), "Input is longer than 32 bytes");
+}
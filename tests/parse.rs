@@ -10,14 +10,18 @@ fn ssh_user_ports() {
         subgroups: None,
         organization: None,
         fullname: "user/project-name".to_string(),
+        helper: None,
         scheme: Scheme::Ssh,
         auth_user: Some("git".to_string()),
         auth_token: None,
         port: Some(9999),
         path: "user/project-name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::Generic,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -35,14 +39,18 @@ fn https_user_bitbucket() {
         subgroups: None,
         organization: None,
         fullname: "user/repo".to_string(),
+        helper: None,
         scheme: Scheme::Https,
         auth_user: Some("user".to_string()),
         auth_token: None,
         port: None,
         path: "/user/repo.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::BitbucketCloud,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -59,14 +67,18 @@ fn ssh_user_bitbucket() {
         subgroups: None,
         organization: None,
         fullname: "user/repo".to_string(),
+        helper: None,
         scheme: Scheme::Ssh,
         auth_user: Some("git".to_string()),
         auth_token: None,
         port: None,
         path: "user/repo.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: false,
-        _skip_part_count: 0,
+        provider: Provider::BitbucketCloud,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -83,14 +95,18 @@ fn https_user_auth_bitbucket() {
         subgroups: None,
         organization: None,
         fullname: "owner/name".to_string(),
+        helper: None,
         scheme: Scheme::Https,
         auth_user: Some("x-token-auth".to_string()),
         auth_token: Some("token".to_string()),
         port: None,
         path: "/owner/name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::BitbucketCloud,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -107,14 +123,18 @@ fn https_user_gitlab() {
         subgroups: None,
         organization: None,
         fullname: "user/repo".to_string(),
+        helper: None,
         scheme: Scheme::Https,
         auth_user: Some("user".to_string()),
         auth_token: None,
         port: None,
         path: "/user/repo.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::GitLab,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -131,14 +151,18 @@ fn ssh_user_gitlab() {
         subgroups: None,
         organization: None,
         fullname: "user/repo".to_string(),
+        helper: None,
         scheme: Scheme::Ssh,
         auth_user: Some("git".to_string()),
         auth_token: None,
         port: None,
         path: "user/repo.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: false,
-        _skip_part_count: 0,
+        provider: Provider::GitLab,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -155,14 +179,18 @@ fn https_user_ports_gitlab() {
         subgroups: None,
         organization: None,
         fullname: "user/repo".to_string(),
+        helper: None,
         scheme: Scheme::Https,
         auth_user: Some("user".to_string()),
         auth_token: None,
         port: Some(8433),
         path: "/user/repo.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::GitLab,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -179,14 +207,18 @@ fn ssh_user_ports_gitlab() {
         subgroups: None,
         organization: None,
         fullname: "user/repo".to_string(),
+        helper: None,
         scheme: Scheme::Ssh,
         auth_user: Some("git".to_string()),
         auth_token: None,
         port: Some(222),
         path: "user/repo.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::GitLab,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -203,14 +235,18 @@ fn https_user_auth_gitlab() {
         subgroups: None,
         organization: None,
         fullname: "owner/name".to_string(),
+        helper: None,
         scheme: Scheme::Https,
         auth_user: Some("x-token-auth".to_string()),
         auth_token: Some("token".to_string()),
         port: None,
         path: "/owner/name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::GitLab,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -227,14 +263,18 @@ fn https_user_auth_ports_gitlab() {
         subgroups: None,
         organization: None,
         fullname: "owner/name".to_string(),
+        helper: None,
         scheme: Scheme::Https,
         auth_user: Some("x-token-auth".to_string()),
         auth_token: Some("token".to_string()),
         port: Some(8433),
         path: "/owner/name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::GitLab,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -251,14 +291,18 @@ fn https_org_project_ports_gitlab() {
         subgroups: None,
         organization: Some("org".to_string()),
         fullname: "org/project/repo".to_string(),
+        helper: None,
         scheme: Scheme::Https,
         auth_user: Some("user".to_string()),
         auth_token: None,
         port: Some(8433),
         path: "/org/project/repo.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::GitLab,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -275,14 +319,18 @@ pub(crate) fn ssh_org_project_ports_gitlab() {
         subgroups: None,
         organization: Some("org".to_string()),
         fullname: "org/project/repo".to_string(),
+        helper: None,
         scheme: Scheme::Ssh,
         auth_user: Some("git".to_string()),
         auth_token: None,
         port: Some(222),
         path: "org/project/repo.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::GitLab,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -299,14 +347,18 @@ fn https_user_github() {
         subgroups: None,
         organization: None,
         fullname: "user/repo".to_string(),
+        helper: None,
         scheme: Scheme::Https,
         auth_user: Some("user".to_string()),
         auth_token: None,
         port: None,
         path: "/user/repo.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::GitHub,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -323,14 +375,18 @@ fn ssh_user_github() {
         subgroups: None,
         organization: None,
         fullname: "user/repo".to_string(),
+        helper: None,
         scheme: Scheme::Ssh,
         auth_user: Some("git".to_string()),
         auth_token: None,
         port: None,
         path: "user/repo.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: false,
-        _skip_part_count: 0,
+        provider: Provider::GitHub,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -347,14 +403,18 @@ fn https_user_auth_github() {
         subgroups: None,
         organization: None,
         fullname: "owner/name".to_string(),
+        helper: None,
         scheme: Scheme::Https,
         auth_user: Some("token".to_string()),
         auth_token: Some("x-oauth-basic".to_string()),
         port: None,
         path: "/owner/name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::GitHub,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -363,7 +423,7 @@ fn https_user_auth_github() {
 #[test]
 fn ssh_user_azure_devops() {
     let test_url = "git@ssh.dev.azure.com:v3/CompanyName/ProjectName/RepoName";
-    let parsed = GitUrl::parse_with_skips(test_url, 1).expect("URL parse failed");
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
     let expected = GitUrl {
         host: Some("ssh.dev.azure.com".to_string()),
         name: "RepoName".to_string(),
@@ -371,14 +431,18 @@ fn ssh_user_azure_devops() {
         subgroups: None,
         organization: Some("CompanyName".to_string()),
         fullname: "CompanyName/ProjectName/RepoName".to_string(),
+        helper: None,
         scheme: Scheme::Ssh,
         auth_user: Some("git".to_string()),
         auth_token: None,
         port: None,
         path: "v3/CompanyName/ProjectName/RepoName".to_string(),
+        path_base: PathBase::Default,
         git_suffix: false,
         scheme_prefix: false,
-        _skip_part_count: 1,
+        provider: Provider::AzureDevOps,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -391,19 +455,22 @@ fn https_user_azure_devops() {
     let expected = GitUrl {
         host: Some("dev.azure.com".to_string()),
         name: "repo".to_string(),
-        owner: Some("_git".to_string()),
-        // this is intended. callers should handle this manually.
-        subgroups: Some("project".to_string()),
+        owner: Some("project".to_string()),
+        subgroups: None,
         organization: Some("organization".to_string()),
-        fullname: "organization/project/_git/repo".to_string(),
+        fullname: "organization/project/repo".to_string(),
+        helper: None,
         scheme: Scheme::Https,
         auth_user: Some("organization".to_string()),
         auth_token: None,
         port: None,
         path: "/organization/project/_git/repo".to_string(),
+        path_base: PathBase::Default,
         git_suffix: false,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::AzureDevOps,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -420,14 +487,18 @@ fn ftp_user() {
         subgroups: None,
         organization: None,
         fullname: "user/project-name".to_string(),
+        helper: None,
         scheme: Scheme::Ftp,
         auth_user: Some("git".to_string()),
         auth_token: None,
         port: None,
         path: "/user/project-name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::Generic,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -444,14 +515,18 @@ fn ftps_user() {
         subgroups: None,
         organization: None,
         fullname: "user/project-name".to_string(),
+        helper: None,
         scheme: Scheme::Ftps,
         auth_user: Some("git".to_string()),
         auth_token: None,
         port: None,
         path: "/user/project-name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::Generic,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -468,14 +543,18 @@ fn relative_unix_path() {
         subgroups: None,
         organization: None,
         fullname: "project-name".to_string(),
+        helper: None,
         scheme: Scheme::File,
         auth_user: None,
         auth_token: None,
         port: None,
         path: "../project-name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: false,
-        _skip_part_count: 0,
+        provider: Provider::Generic,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -492,14 +571,18 @@ fn absolute_unix_path() {
         subgroups: None,
         organization: None,
         fullname: "project-name".to_string(),
+        helper: None,
         scheme: Scheme::File,
         auth_user: None,
         auth_token: None,
         port: None,
         path: "/path/to/project-name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: false,
-        _skip_part_count: 0,
+        provider: Provider::Generic,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -517,14 +600,18 @@ fn relative_windows_path() {
         subgroups: None,
         organization: None,
         fullname: "project-name".to_string(),
+        helper: None,
         scheme: Scheme::File,
         auth_user: None,
         auth_token: None,
         port: None,
         path: "../project-name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: false,
-        _skip_part_count: 0,
+        provider: Provider::Generic,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -543,14 +630,18 @@ fn absolute_windows_path() {
         subgroups: None,
         organization: None,
         fullname: "project-name".to_string(),
+        helper: None,
         scheme: Scheme::File,
         auth_user: None,
         auth_token: None,
         port: None,
         path: "c:\\project-name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::Generic,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -579,14 +670,18 @@ fn ssh_without_organization() {
         subgroups: None,
         organization: None,
         fullname: "repo/repo".to_string(),
+        helper: None,
         scheme: Scheme::Ssh,
         auth_user: None,
         auth_token: None,
         port: Some(29418),
         path: "repo".to_string(),
+        path_base: PathBase::Default,
         git_suffix: false,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::Gerrit,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -606,10 +701,24 @@ fn bad_port_number() {
     let e = GitUrl::parse(test_url);
 
     assert!(e.is_err());
+    let e = e.err().unwrap();
     assert_eq!(
-        format!("{}", e.err().unwrap()),
+        format!("{}", e),
         "Error from Url crate: invalid port number"
     );
+
+    let context = e.with_input(test_url);
+    assert_eq!(
+        format!("{}", context),
+        "port `crypto-browserify` is not numeric, did you mean `github.com:crypto-browserify/browserify-rsa.git`?"
+    );
+    assert_eq!(
+        context.error,
+        GitUrlParseError::NonNumericPort("crypto-browserify".to_string())
+    );
+    assert_eq!(context.input, test_url);
+    assert_eq!(context.span, 19..36);
+    assert_eq!(context.component(), "crypto-browserify");
 }
 
 #[test]
@@ -623,14 +732,18 @@ fn git() {
         subgroups: None,
         organization: None,
         fullname: "owner/name".to_string(),
+        helper: None,
         scheme: Scheme::Git,
         auth_user: None,
         auth_token: None,
         port: None,
         path: "/owner/name.git".to_string(),
+        path_base: PathBase::Default,
         git_suffix: true,
         scheme_prefix: true,
-        _skip_part_count: 0,
+        provider: Provider::GitHub,
+        extra: None,
+        source: None,
     };
 
     assert_eq!(parsed, expected);
@@ -0,0 +1,97 @@
+use git_url_parse::*;
+
+#[test]
+fn public_hosts() {
+    let cases = [
+        ("git@github.com:owner/repo.git", Provider::GitHub),
+        (
+            "https://gitlab.com/group/subgroup/repo.git",
+            Provider::GitLab,
+        ),
+        (
+            "https://bitbucket.org/owner/repo.git",
+            Provider::BitbucketCloud,
+        ),
+        ("https://codeberg.org/owner/repo.git", Provider::Gitea),
+        ("https://git.sr.ht/~owner/repo", Provider::SourceHut),
+        (
+            "https://chromium.googlesource.com/chromium/src.git",
+            Provider::Gerrit,
+        ),
+        ("https://host.tld/owner/repo.git", Provider::Generic),
+    ];
+
+    for (test_url, expected) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        assert_eq!(parsed.provider, expected, "{}", test_url);
+    }
+}
+
+#[test]
+fn azure_devops_forms() {
+    let cases = [
+        "https://organization@dev.azure.com/organization/project/_git/repo",
+        "git@ssh.dev.azure.com:v3/CompanyName/ProjectName/RepoName",
+        "https://org.visualstudio.com/DefaultCollection/project/_git/repo",
+        "https://tfs.corp.example/tfs/Collection/project/_git/repo",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        assert_eq!(parsed.provider, Provider::AzureDevOps, "{}", test_url);
+    }
+}
+
+#[test]
+fn bitbucket_server_path_and_port() {
+    let https =
+        GitUrl::parse("https://git.corp.example/scm/proj/repo.git").expect("URL parse failed");
+    assert_eq!(https.provider, Provider::BitbucketServer);
+
+    let ssh =
+        GitUrl::parse("ssh://git@git.corp.example:7999/proj/repo.git").expect("URL parse failed");
+    assert_eq!(ssh.provider, Provider::BitbucketServer);
+}
+
+#[test]
+fn gerrit_port() {
+    let parsed = GitUrl::parse("ssh://user@review.corp.example:29418/project/repo")
+        .expect("URL parse failed");
+    assert_eq!(parsed.provider, Provider::Gerrit);
+}
+
+#[test]
+fn file_is_generic() {
+    let parsed = GitUrl::parse("file:///path/to/repo.git").expect("URL parse failed");
+    assert_eq!(parsed.provider, Provider::Generic);
+}
+
+#[test]
+fn registered_host_overrides_detection() {
+    let mut registry = HostRegistry::new();
+    registry
+        .register("Code.Corp.Example", Provider::GitLab)
+        .register("github.com", Provider::Generic);
+
+    let parsed = GitUrl::parse_with_registry("git@code.corp.example:group/repo.git", &registry)
+        .expect("URL parse failed");
+    assert_eq!(parsed.provider, Provider::GitLab);
+
+    let parsed = GitUrl::parse_with_registry("https://github.com/owner/repo", &registry)
+        .expect("URL parse failed");
+    assert_eq!(parsed.provider, Provider::Generic);
+}
+
+#[test]
+fn provider_round_trips_through_str() {
+    for provider in [
+        Provider::GitHub,
+        Provider::BitbucketServer,
+        Provider::AzureDevOps,
+        Provider::Generic,
+    ] {
+        let parsed: Provider = provider.to_string().parse().expect("Provider parse failed");
+        assert_eq!(parsed, provider);
+    }
+    assert_eq!(Provider::GitHub.to_string(), "github");
+}
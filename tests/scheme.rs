@@ -0,0 +1,90 @@
+use git_url_parse::*;
+use std::str::FromStr;
+
+#[test]
+fn round_trip() {
+    let test_urls = [
+        "git+https://github.com/owner/repo.git",
+        "git+http://host.tld/owner/repo",
+        "git+file:///path/to/repo.git",
+        "git+ssh://git@github.com/owner/repo.git",
+        "sftp://user@host.tld/srv/git/repo.git",
+        "rsync://host.tld/srv/git/repo.git",
+    ];
+    for test_url in test_urls {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        assert_eq!(parsed.to_string(), test_url);
+    }
+}
+
+#[test]
+fn git_https() {
+    let test_url = "git+https://token@github.com/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let expected = GitUrl {
+        host: Some("github.com".to_string()),
+        name: "repo".to_string(),
+        owner: Some("owner".to_string()),
+        subgroups: None,
+        organization: None,
+        fullname: "owner/repo".to_string(),
+        helper: None,
+        scheme: Scheme::GitHttps,
+        auth_user: Some("token".to_string()),
+        auth_token: None,
+        port: None,
+        path: "/owner/repo.git".to_string(),
+        path_base: PathBase::Default,
+        git_suffix: true,
+        scheme_prefix: true,
+        provider: Provider::GitHub,
+        extra: None,
+        source: None,
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.to_string(), test_url);
+    assert!(parsed.same_repo(&GitUrl::parse("git@github.com:owner/repo").unwrap()));
+}
+
+#[test]
+fn git_file() {
+    let parsed = GitUrl::parse("git+file:///path/to/repo.git").expect("URL parse failed");
+
+    assert_eq!(parsed.scheme, Scheme::GitFile);
+    assert_eq!(parsed.host, None);
+    assert_eq!(parsed.owner, None);
+    assert_eq!(parsed.name, "repo");
+}
+
+#[test]
+fn names() {
+    for (name, scheme) in [
+        ("git+file", Scheme::GitFile),
+        ("git+http", Scheme::GitHttp),
+        ("git+https", Scheme::GitHttps),
+        ("rsync", Scheme::Rsync),
+        ("sftp", Scheme::Sftp),
+    ] {
+        assert_eq!(Scheme::from_str(name), Ok(scheme.clone()));
+        assert_eq!(scheme.to_string(), name);
+    }
+}
+
+#[test]
+fn default_ports() {
+    assert_eq!(Scheme::GitHttps.default_port(), Some(443));
+    assert_eq!(Scheme::GitHttp.default_port(), Some(80));
+    assert_eq!(Scheme::Sftp.default_port(), Some(22));
+    assert_eq!(Scheme::Rsync.default_port(), Some(873));
+    assert_eq!(Scheme::GitFile.default_port(), None);
+}
+
+#[test]
+fn transport() {
+    assert_eq!(Scheme::GitHttps.transport(), Scheme::Https);
+    assert_eq!(Scheme::GitHttp.transport(), Scheme::Http);
+    assert_eq!(Scheme::GitFile.transport(), Scheme::File);
+    assert_eq!(Scheme::GitSsh.transport(), Scheme::Ssh);
+    assert_eq!(Scheme::Rsync.transport(), Scheme::Rsync);
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "serde")]
+
+use git_url_parse::*;
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn structured() {
+    let test_url = "git@github.com:XieJiSS/git-url-parse-rs.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    let json = serde_json::to_value(&parsed).expect("Serialize failed");
+    let expected = serde_json::json!({
+        "host": "github.com",
+        "name": "git-url-parse-rs",
+        "owner": "XieJiSS",
+        "subgroups": null,
+        "organization": null,
+        "fullname": "XieJiSS/git-url-parse-rs",
+        "helper": null,
+        "scheme": "ssh",
+        "auth_user": "git",
+        "auth_token": null,
+        "port": null,
+        "path": "XieJiSS/git-url-parse-rs.git",
+        "path_base": null,
+        "git_suffix": true,
+        "scheme_prefix": false,
+        "provider": "github",
+        "extra": null
+    });
+    assert_eq!(json, expected);
+
+    let deserialized: GitUrl = serde_json::from_value(json).expect("Deserialize failed");
+    assert_eq!(deserialized, parsed);
+}
+
+#[test]
+fn path_base() {
+    let parsed = GitUrl::parse("ssh://host.tld/~user/repo.git").expect("URL parse failed");
+    let json = serde_json::to_value(&parsed).expect("Serialize failed");
+
+    assert_eq!(json["path_base"], "~user");
+    let deserialized: GitUrl = serde_json::from_value(json).expect("Deserialize failed");
+    assert_eq!(deserialized, parsed);
+}
+
+#[test]
+fn scheme_and_provider_names() {
+    assert_eq!(
+        serde_json::to_string(&Scheme::GitSsh).expect("Serialize failed"),
+        "\"git+ssh\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Provider>("\"azure-devops\"").expect("Deserialize failed"),
+        Provider::AzureDevOps
+    );
+    assert_eq!(
+        serde_json::from_str::<Scheme>("\"gopher\"").expect("Deserialize failed"),
+        Scheme::Other("gopher".to_string())
+    );
+    assert!(serde_json::from_str::<Provider>("\"gopher\"").is_err());
+}
+
+#[test]
+fn string_mode() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Remote {
+        name: String,
+        #[serde(with = "git_url_parse::serde_str")]
+        url: GitUrl,
+        #[serde(default, with = "git_url_parse::serde_str::option")]
+        push_url: Option<GitUrl>,
+    }
+
+    let remote = Remote {
+        name: "origin".to_string(),
+        url: GitUrl::parse("https://github.com/owner/repo.git").expect("URL parse failed"),
+        push_url: Some(GitUrl::parse("git@github.com:owner/repo.git").expect("URL parse failed")),
+    };
+
+    let json = serde_json::to_string(&remote).expect("Serialize failed");
+    assert_eq!(
+        json,
+        r#"{"name":"origin","url":"https://github.com/owner/repo.git","push_url":"git@github.com:owner/repo.git"}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Remote>(&json).expect("Deserialize failed"),
+        remote
+    );
+
+    let without_push_url: Remote =
+        serde_json::from_str(r#"{"name":"origin","url":"https://github.com/owner/repo.git"}"#)
+            .expect("Deserialize failed");
+    assert_eq!(without_push_url.push_url, None);
+
+    let e = serde_json::from_str::<Remote>(r#"{"name":"origin","url":"git@test.com:repo"}"#);
+    assert!(e.is_err());
+}
+
+#[test]
+fn error() {
+    let test_url = "git@test.com:repo";
+    let e = GitUrl::parse(test_url).expect_err("URL parse should fail");
+
+    assert_eq!(
+        serde_json::to_value(&e).expect("Serialize failed"),
+        serde_json::json!({
+            "kind": "UnexpectedFormat",
+            "message": "Git Url not in expected format"
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(e.with_input(test_url)).expect("Serialize failed"),
+        serde_json::json!({
+            "kind": "UnexpectedFormat",
+            "message": "Git Url not in expected format",
+            "input": "git@test.com:repo",
+            "span": [13, 17],
+            "suggestion": null
+        })
+    );
+}
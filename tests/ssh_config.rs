@@ -0,0 +1,143 @@
+use git_url_parse::*;
+
+const CONFIG: &str = r#"
+# Work account
+Host work-gh
+    HostName ssh.github.com
+    User git
+    Port 443
+    IdentityFile ~/.ssh/work
+
+Host gl-* !gl-private
+    HostName=%h.example.com
+    Port 2222
+
+Host *
+    User me
+    Port 22
+"#;
+
+#[test]
+fn lookup() {
+    let config = SshConfig::parse(CONFIG).expect("Config parse failed");
+
+    assert_eq!(
+        config.lookup("work-gh"),
+        SshHost {
+            alias: "work-gh".to_string(),
+            host_name: "ssh.github.com".to_string(),
+            user: Some("git".to_string()),
+            port: Some(443),
+        }
+    );
+    assert_eq!(
+        config.lookup("gl-mirror"),
+        SshHost {
+            alias: "gl-mirror".to_string(),
+            host_name: "gl-mirror.example.com".to_string(),
+            user: Some("me".to_string()),
+            port: Some(2222),
+        }
+    );
+    assert_eq!(config.lookup("gl-private").host_name, "gl-private");
+    assert_eq!(config.lookup("gl-private").port, Some(22));
+}
+
+#[test]
+fn resolve_scp_like_alias() {
+    let config = SshConfig::parse(CONFIG).expect("Config parse failed");
+    let parsed = GitUrl::parse("work-gh:org/repo.git").expect("URL parse failed");
+    let resolved = config.resolve(&parsed).expect("Resolve failed");
+
+    assert_eq!(resolved.alias, "work-gh");
+    assert_eq!(
+        resolved.url.to_string(),
+        "ssh://git@ssh.github.com:443/org/repo.git"
+    );
+    assert_eq!(resolved.url.provider, Provider::GitHub);
+    assert_eq!(resolved.url.fullname, "org/repo");
+}
+
+#[test]
+fn url_user_and_port_take_precedence() {
+    let config = SshConfig::parse(CONFIG).expect("Config parse failed");
+    let parsed = GitUrl::parse("ssh://deploy@work-gh:22/org/repo.git").expect("URL parse failed");
+    let resolved = config.resolve(&parsed).expect("Resolve failed");
+
+    assert_eq!(
+        resolved.url.to_string(),
+        "ssh://deploy@ssh.github.com:22/org/repo.git"
+    );
+}
+
+#[test]
+fn non_ssh_urls_are_unchanged() {
+    let config = SshConfig::parse(CONFIG).expect("Config parse failed");
+    let parsed = GitUrl::parse("https://work-gh/org/repo.git").expect("URL parse failed");
+    let resolved = config.resolve(&parsed).expect("Resolve failed");
+
+    assert_eq!(resolved.url, parsed);
+}
+
+#[test]
+fn includes() {
+    let text = "Include conf.d/*\n\nHost *\n    User fallback\n";
+    let config = SshConfig::parse_with_includes(text, |pattern| {
+        assert_eq!(pattern, "conf.d/*");
+        Ok(vec![
+            "Host gitlab\n    HostName gitlab.com\n    User git\n".to_string()
+        ])
+    })
+    .expect("Config parse failed");
+
+    let parsed = GitUrl::parse("gitlab:group/repo.git").expect("URL parse failed");
+    let resolved = config.resolve(&parsed).expect("Resolve failed");
+
+    assert_eq!(resolved.url.to_string(), "git@gitlab.com:group/repo.git");
+    assert_eq!(resolved.url.provider, Provider::GitLab);
+    assert_eq!(config.lookup("other").user, Some("fallback".to_string()));
+}
+
+#[test]
+fn options_after_include_stay_in_their_block() {
+    let text = "Host work-gh\n    Include x\n    HostName github.com\n    User git\n";
+    let config = SshConfig::parse_with_includes(text, |_| {
+        Ok(vec!["Host other\n    Port 2222\n".to_string()])
+    })
+    .expect("Config parse failed");
+
+    let work = config.lookup("work-gh");
+    assert_eq!(work.host_name, "github.com");
+    assert_eq!(work.user, Some("git".to_string()));
+    assert_eq!(work.port, None);
+
+    let other = config.lookup("other");
+    assert_eq!(other.host_name, "other");
+    assert_eq!(other.user, None);
+    assert_eq!(other.port, Some(2222));
+}
+
+#[test]
+fn load_follows_includes() {
+    let dir = std::env::temp_dir().join(format!("git-url-parse-ssh-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("conf.d")).expect("Failed to create dir");
+    std::fs::write(dir.join("config"), "Include conf.d/*.conf\n").expect("Failed to write");
+    std::fs::write(
+        dir.join("conf.d/work.conf"),
+        "Host work\n    HostName github.com\n",
+    )
+    .expect("Failed to write");
+
+    let config = SshConfig::load(dir.join("config")).expect("Config load failed");
+    std::fs::remove_dir_all(&dir).expect("Failed to remove dir");
+
+    assert_eq!(config.lookup("work").host_name, "github.com");
+}
+
+#[test]
+fn invalid_config() {
+    assert_eq!(
+        SshConfig::parse("Host a\n    Port https\n").unwrap_err(),
+        GitUrlParseError::InvalidSshConfig(2)
+    );
+}
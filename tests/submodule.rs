@@ -0,0 +1,103 @@
+use git_url_parse::*;
+
+fn join(remote: &str, relative: &str) -> String {
+    GitUrl::parse(remote)
+        .expect("URL parse failed")
+        .join(relative)
+        .expect("URL join failed")
+        .to_string()
+}
+
+#[test]
+fn join_https() {
+    assert_eq!(
+        join("https://github.com/org/app.git", "../lib.git"),
+        "https://github.com/org/lib.git"
+    );
+    assert_eq!(
+        join("https://github.com/org/app", "../../other/lib"),
+        "https://github.com/other/lib"
+    );
+    assert_eq!(
+        join("https://github.com/org/app", "./lib"),
+        "https://github.com/org/app/lib"
+    );
+}
+
+#[test]
+fn join_scp_like() {
+    assert_eq!(
+        join("git@github.com:org/app.git", "../lib.git"),
+        "git@github.com:org/lib.git"
+    );
+    // Going up past the first component keeps the ':'
+    assert_eq!(
+        join("git@host.example.com:org/app.git", "../../other/lib.git"),
+        "git@host.example.com:other/lib.git"
+    );
+    assert_eq!(
+        join("ssh://git@github.com/org/app.git", "../lib.git/"),
+        "ssh://git@github.com/org/lib.git"
+    );
+}
+
+#[test]
+fn join_local() {
+    assert_eq!(join("/srv/git/app.git", "../lib.git"), "/srv/git/lib.git");
+    assert_eq!(join("../app", "../lib"), "../lib");
+}
+
+#[test]
+fn join_absolute() {
+    assert_eq!(
+        join("https://github.com/org/app", "git@gitlab.com:group/lib.git"),
+        "git@gitlab.com:group/lib.git"
+    );
+}
+
+#[test]
+fn join_above_root() {
+    let remote = GitUrl::parse("https://github.com/org/app").expect("URL parse failed");
+    assert_eq!(
+        remote.join("../../../lib"),
+        Err(GitUrlParseError::RelativeUrlAboveRoot(
+            "https://github.com/org/app".to_string()
+        ))
+    );
+}
+
+#[test]
+fn gitmodules() {
+    let text = r#"
+[submodule "lib"]
+	path = vendor/lib
+	url = ../lib.git
+	branch = main
+[submodule "docs"]
+	path = docs
+	url = https://gitlab.com/group/docs.git
+"#;
+    let remote = GitUrl::parse("git@github.com:org/app.git").expect("URL parse failed");
+    let submodules = Submodule::parse_gitmodules(text, &remote).expect("Parse failed");
+
+    assert_eq!(submodules.len(), 2);
+    assert_eq!(submodules[0].name, "lib");
+    assert_eq!(submodules[0].path, "vendor/lib");
+    assert_eq!(submodules[0].branch, Some("main".to_string()));
+    assert_eq!(submodules[0].url.to_string(), "git@github.com:org/lib.git");
+    assert_eq!(submodules[0].url.fullname, "org/lib");
+    assert_eq!(submodules[1].name, "docs");
+    assert_eq!(submodules[1].branch, None);
+    assert_eq!(submodules[1].url.provider, Provider::GitLab);
+}
+
+#[test]
+fn gitmodules_missing_url() {
+    let text = "[submodule \"lib\"]\n\tpath = lib\n";
+    let remote = GitUrl::parse("git@github.com:org/app.git").expect("URL parse failed");
+
+    assert_eq!(
+        Submodule::parse_gitmodules(text, &remote),
+        Err(GitUrlParseError::InvalidConfig(2))
+    );
+}
@@ -0,0 +1,116 @@
+use git_url_parse::*;
+
+#[test]
+fn forced_git_https_with_subdir() {
+    let test_source = "git::https://example.com/org/net.git//modules/vpc?ref=v1.2.0&depth=1";
+    let parsed = TerraformSource::parse(test_source).expect("Source parse failed");
+    let expected = TerraformSource {
+        repo: GitUrl {
+            host: Some("example.com".to_string()),
+            name: "net".to_string(),
+            owner: Some("org".to_string()),
+            subgroups: None,
+            organization: None,
+            fullname: "org/net".to_string(),
+            helper: None,
+            scheme: Scheme::Https,
+            auth_user: None,
+            auth_token: None,
+            port: None,
+            path: "/org/net.git".to_string(),
+            path_base: PathBase::Default,
+            git_suffix: true,
+            scheme_prefix: true,
+            provider: Provider::Generic,
+            extra: None,
+            source: None,
+        },
+        subdir: Some("modules/vpc".to_string()),
+        git_ref: Some("v1.2.0".to_string()),
+        depth: Some(1),
+        sshkey: None,
+        params: vec![],
+    };
+
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.to_string(), test_source);
+}
+
+#[test]
+fn forced_git_scp_like() {
+    let test_source = "git::git@github.com:org/mod.git";
+    let parsed = TerraformSource::parse(test_source).expect("Source parse failed");
+
+    assert_eq!(parsed.repo.provider, Provider::GitHub);
+    assert_eq!(parsed.repo.owner, Some("org".to_string()));
+    assert_eq!(parsed.repo.name, "mod");
+    assert_eq!(parsed.subdir, None);
+    assert_eq!(parsed.to_string(), test_source);
+}
+
+#[test]
+fn single_segment_repo() {
+    let parsed = TerraformSource::parse("git::https://example.com/net.git//modules/vpc")
+        .expect("Source parse failed");
+
+    assert_eq!(parsed.repo.name, "net");
+    assert_eq!(parsed.repo.path, "/net.git");
+    assert_eq!(parsed.subdir, Some("modules/vpc".to_string()));
+}
+
+#[test]
+fn detected_git_sources() {
+    let scp_like = TerraformSource::parse("git@github.com:org/mod.git//sub?ref=main")
+        .expect("Source parse failed");
+    assert_eq!(scp_like.repo.fullname, "org/mod");
+    assert_eq!(scp_like.subdir, Some("sub".to_string()));
+    assert_eq!(scp_like.git_ref, Some("main".to_string()));
+
+    let github: TerraformSource = "github.com/hashicorp/example//modules/consul?ref=v1"
+        .parse()
+        .expect("Source parse failed");
+    assert_eq!(github.repo.scheme, Scheme::Https);
+    assert_eq!(github.repo.fullname, "hashicorp/example");
+    assert_eq!(
+        github.to_string(),
+        "git::https://github.com/hashicorp/example//modules/consul?ref=v1"
+    );
+}
+
+#[test]
+fn sshkey_and_other_params() {
+    let parsed = TerraformSource::parse(
+        "git::ssh://git@example.com/org/storage.git?sshkey=a2V5%2B%2F%3D&ref=feature%2Fx&foo=bar",
+    )
+    .expect("Source parse failed");
+
+    assert_eq!(parsed.sshkey, Some("a2V5+/=".to_string()));
+    assert_eq!(parsed.git_ref, Some("feature/x".to_string()));
+    assert_eq!(parsed.params, vec![("foo".to_string(), "bar".to_string())]);
+    assert_eq!(
+        parsed.to_string(),
+        "git::ssh://git@example.com/org/storage.git?ref=feature/x&sshkey=a2V5%2B/%3D&foo=bar"
+    );
+}
+
+#[test]
+fn non_git_sources() {
+    assert_eq!(
+        TerraformSource::parse("hg::http://example.com/vpc.hg"),
+        Err(GitUrlParseError::UnsupportedGetter("hg".to_string()))
+    );
+    assert_eq!(
+        TerraformSource::parse("hashicorp/consul/aws"),
+        Err(GitUrlParseError::UnexpectedFormat)
+    );
+    assert_eq!(
+        TerraformSource::parse("https://example.com/vpc-module.zip"),
+        Err(GitUrlParseError::UnexpectedFormat)
+    );
+    assert_eq!(
+        TerraformSource::parse("git::https://example.com/org/vpc.git?depth=full"),
+        Err(GitUrlParseError::InvalidSourceParameter(
+            "depth".to_string()
+        ))
+    );
+}
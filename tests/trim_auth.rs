@@ -99,3 +99,14 @@ fn https_user_azure_devops() {
 
     assert_eq!(format!("{}", parsed_and_trimmed), expected);
 }
+
+#[test]
+fn ssh_user_no_port() {
+    let test_url = "ssh://git@host.tld/user/project-name.git";
+    let parsed_and_trimmed = GitUrl::parse(test_url)
+        .expect("URL parse failed")
+        .trim_auth();
+    let expected = "ssh://host.tld/user/project-name.git";
+
+    assert_eq!(format!("{}", parsed_and_trimmed), expected);
+}
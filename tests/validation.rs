@@ -0,0 +1,146 @@
+use git_url_parse::*;
+
+fn kinds(url: &str, rules: &ProviderRules) -> Vec<(NameComponent, ViolationKind)> {
+    GitUrl::parse(url)
+        .expect("URL parse failed")
+        .validate(rules)
+        .into_iter()
+        .map(|v| (v.component, v.kind))
+        .collect()
+}
+
+#[test]
+fn valid_names() {
+    for test_url in [
+        "https://github.com/tjtelan/git-url-parse-rs.git",
+        "git@gitlab.com:group/sub_group/project.name.git",
+        "https://dev.azure.com/org/My%20Project/_git/My%20Repo",
+        "git@bitbucket.org:workspace/repo-slug.git",
+        "https://bitbucket.example.com/scm/PROJ_1/repo.git",
+        "https://bitbucket.example.com/scm/~user/repo.git",
+        "https://host.tld/any thing/goes here",
+    ] {
+        assert_eq!(
+            kinds(test_url, &ProviderRules::new()),
+            vec![],
+            "{}",
+            test_url
+        );
+    }
+}
+
+#[test]
+fn github() {
+    let rules = ProviderRules::new();
+    assert_eq!(
+        kinds("https://github.com/settings/repo", &rules),
+        vec![(NameComponent::Owner, ViolationKind::Reserved)]
+    );
+    assert_eq!(
+        kinds(
+            &format!("https://github.com/{}/repo", "a".repeat(40)),
+            &rules
+        ),
+        vec![(NameComponent::Owner, ViolationKind::TooLong(39))]
+    );
+    assert_eq!(
+        kinds("https://github.com/-my--name/re+po", &rules),
+        vec![
+            (NameComponent::Owner, ViolationKind::InvalidStart('-')),
+            (NameComponent::Owner, ViolationKind::RepeatedCharacter('-')),
+            (NameComponent::Name, ViolationKind::InvalidCharacter('+')),
+        ]
+    );
+
+    // `owner/..git` is the repo `.`
+    for test_url in [
+        "https://github.com/owner/..git",
+        "git@github.com:owner/...git",
+    ] {
+        assert_eq!(
+            kinds(test_url, &rules),
+            vec![(NameComponent::Name, ViolationKind::Reserved)],
+            "{}",
+            test_url
+        );
+    }
+}
+
+#[test]
+fn gitlab() {
+    let rules = ProviderRules::new();
+    assert_eq!(
+        kinds("https://gitlab.com/group/-/project", &rules),
+        vec![(NameComponent::Owner, ViolationKind::Reserved)]
+    );
+    assert_eq!(
+        kinds("https://gitlab.com/api/project", &rules),
+        vec![(NameComponent::Owner, ViolationKind::Reserved)]
+    );
+    assert_eq!(
+        kinds("https://gitlab.com/group/api/feed.atom/project", &rules),
+        vec![(NameComponent::Owner, ViolationKind::InvalidSuffix(".atom"))]
+    );
+}
+
+#[test]
+fn azure_devops() {
+    let rules = ProviderRules::new();
+    assert_eq!(
+        kinds("https://dev.azure.com/org/_Project/_git/repo.", &rules),
+        vec![
+            (NameComponent::Owner, ViolationKind::InvalidStart('_')),
+            (NameComponent::Name, ViolationKind::InvalidEnd('.')),
+        ]
+    );
+    assert_eq!(
+        kinds("https://dev.azure.com/org/CON/_git/a%3Fb", &rules),
+        vec![
+            (NameComponent::Owner, ViolationKind::Reserved),
+            (NameComponent::Name, ViolationKind::InvalidCharacter('?')),
+        ]
+    );
+}
+
+#[test]
+fn bitbucket() {
+    let rules = ProviderRules::new();
+    assert_eq!(
+        kinds("https://bitbucket.org/Workspace/Repo.git", &rules),
+        vec![
+            (NameComponent::Owner, ViolationKind::Uppercase),
+            (NameComponent::Name, ViolationKind::Uppercase),
+        ]
+    );
+    assert_eq!(
+        kinds("https://bitbucket.example.com/scm/1proj/repo.git", &rules),
+        vec![(NameComponent::Owner, ViolationKind::InvalidStart('1'))]
+    );
+}
+
+#[test]
+fn other_provider_and_reserved_names() {
+    // The rules of the forge a repo is mirrored to
+    let rules = ProviderRules::new().provider(Provider::BitbucketCloud);
+    assert_eq!(
+        kinds("https://host.tld/Org/repo", &rules),
+        vec![(NameComponent::Owner, ViolationKind::Uppercase)]
+    );
+
+    let rules = ProviderRules::new().reserve("internal");
+    assert_eq!(
+        kinds("https://host.tld/Internal/repo", &rules),
+        vec![(NameComponent::Owner, ViolationKind::Reserved)]
+    );
+}
+
+#[test]
+fn violation_display() {
+    let url = GitUrl::parse("https://github.com/orgs/repo").expect("URL parse failed");
+    let violations: Vec<String> = url
+        .validate(&ProviderRules::new())
+        .iter()
+        .map(|v| v.to_string())
+        .collect();
+    assert_eq!(violations, vec!["owner `orgs` is reserved"]);
+}
@@ -0,0 +1,175 @@
+use git_url_parse::*;
+
+#[test]
+fn github_from_ssh() {
+    let parsed = GitUrl::parse("git@github.com:owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(
+        parsed.web_url(),
+        Some("https://github.com/owner/repo".to_string())
+    );
+    assert_eq!(
+        parsed.blob_url(
+            "main",
+            "src/lib.rs",
+            Some(LineRange {
+                start: 10,
+                end: Some(20)
+            })
+        ),
+        Some("https://github.com/owner/repo/blob/main/src/lib.rs#L10-L20".to_string())
+    );
+    assert_eq!(
+        parsed.raw_url("v1.2", "README.md"),
+        Some("https://raw.githubusercontent.com/owner/repo/v1.2/README.md".to_string())
+    );
+    assert_eq!(
+        parsed.commit_url("0123abc"),
+        Some("https://github.com/owner/repo/commit/0123abc".to_string())
+    );
+}
+
+#[test]
+fn gitlab_subgroups() {
+    let parsed =
+        GitUrl::parse("git@gitlab.com:group/sub1/sub2/repo.git").expect("URL parse failed");
+
+    assert_eq!(
+        parsed.web_url(),
+        Some("https://gitlab.com/group/sub1/sub2/repo".to_string())
+    );
+    assert_eq!(
+        parsed.blob_url(
+            "main",
+            "x.rs",
+            Some(LineRange {
+                start: 3,
+                end: None
+            })
+        ),
+        Some("https://gitlab.com/group/sub1/sub2/repo/-/blob/main/x.rs#L3".to_string())
+    );
+    assert_eq!(
+        parsed.raw_url("main", "x.rs"),
+        Some("https://gitlab.com/group/sub1/sub2/repo/-/raw/main/x.rs".to_string())
+    );
+    assert_eq!(
+        parsed.commit_url("0123abc"),
+        Some("https://gitlab.com/group/sub1/sub2/repo/-/commit/0123abc".to_string())
+    );
+}
+
+#[test]
+fn bitbucket_cloud() {
+    let parsed =
+        GitUrl::parse("https://user@bitbucket.org/owner/repo.git").expect("URL parse failed");
+
+    assert_eq!(
+        parsed.blob_url(
+            "main",
+            "a b.txt",
+            Some(LineRange {
+                start: 1,
+                end: Some(2)
+            })
+        ),
+        Some("https://bitbucket.org/owner/repo/src/main/a%20b.txt#lines-1:2".to_string())
+    );
+    assert_eq!(
+        parsed.commit_url("0123abc"),
+        Some("https://bitbucket.org/owner/repo/commits/0123abc".to_string())
+    );
+}
+
+#[test]
+fn bitbucket_server_from_ssh() {
+    let parsed = GitUrl::parse("ssh://git@bitbucket.corp.example:7999/proj/repo.git")
+        .expect("URL parse failed");
+
+    assert_eq!(
+        parsed.web_url(),
+        Some("https://bitbucket.corp.example/projects/proj/repos/repo".to_string())
+    );
+    assert_eq!(
+        parsed.blob_url("feature/x", "src/main.c", None),
+        Some(
+            "https://bitbucket.corp.example/projects/proj/repos/repo/browse/src/main.c?at=feature/x"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn gitea_branch_and_commit() {
+    let parsed = GitUrl::parse("https://codeberg.org/owner/repo.git").expect("URL parse failed");
+    let sha = "0123456789abcdef0123456789abcdef01234567";
+
+    assert_eq!(
+        parsed.blob_url("main", "README.md", None),
+        Some("https://codeberg.org/owner/repo/src/branch/main/README.md".to_string())
+    );
+    assert_eq!(
+        parsed.blob_url(sha, "README.md", None),
+        Some(format!(
+            "https://codeberg.org/owner/repo/src/commit/{}/README.md",
+            sha
+        ))
+    );
+    assert_eq!(
+        parsed.raw_url("main", "README.md"),
+        Some("https://codeberg.org/owner/repo/raw/branch/main/README.md".to_string())
+    );
+}
+
+#[test]
+fn azure_devops_from_ssh() {
+    let parsed =
+        GitUrl::parse("git@ssh.dev.azure.com:v3/org/project/repo").expect("URL parse failed");
+
+    assert_eq!(
+        parsed.web_url(),
+        Some("https://dev.azure.com/org/project/_git/repo".to_string())
+    );
+    assert_eq!(
+        parsed.blob_url(
+            "main",
+            "/src/app.ts",
+            Some(LineRange {
+                start: 4,
+                end: Some(8)
+            })
+        ),
+        Some(
+            "https://dev.azure.com/org/project/_git/repo?path=/src/app.ts&version=GBmain&line=4&lineEnd=8"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn blob_url_round_trips_through_location() {
+    let parsed = GitUrl::parse("git@gitlab.com:group/sub/repo.git").expect("URL parse failed");
+    let lines = Some(LineRange {
+        start: 7,
+        end: Some(9),
+    });
+    let blob_url = parsed
+        .blob_url("main", "docs/index.md", lines)
+        .expect("No blob url");
+    let location = RepoLocation::parse(&blob_url).expect("URL parse failed");
+
+    assert_eq!(location.repo.fullname, parsed.fullname);
+    assert_eq!(location.git_ref, Some("main".to_string()));
+    assert_eq!(location.path, Some("docs/index.md".to_string()));
+    assert_eq!(location.lines, lines);
+}
+
+#[test]
+fn generic_and_file_have_no_web_url() {
+    let generic = GitUrl::parse("https://host.tld/owner/repo.git").expect("URL parse failed");
+    assert_eq!(generic.web_url(), None);
+    assert_eq!(generic.blob_url("main", "README.md", None), None);
+
+    let file = GitUrl::parse("file:///path/to/repo.git").expect("URL parse failed");
+    assert_eq!(file.web_url(), None);
+}